@@ -0,0 +1,221 @@
+//! Convenience methods to set the metadata and the instructions which are shared by most of the
+//! guitar courses.
+
+use std::collections::BTreeMap;
+
+use trane::{
+    course_builder::{music::MusicMetadata, AssetBuilder, CourseBuilder, TraneMetadata},
+    data::BasicAsset,
+};
+use ustr::Ustr;
+
+use crate::{
+    constants::COURSE_VERSION,
+    metadata::{add_genres, Genre, RECOMMENDED_NEXT_KEY, TECHNIQUE_KEY, VERSION_KEY},
+    music::GuitarScale,
+};
+
+/// The name of the file containing the instructions of a course.
+const INSTRUCTIONS_FILE: &str = "course_instructions.md";
+
+/// An extension trait for `CourseBuilder` with methods to set the metadata and assets used by the
+/// guitar courses. Metadata values are appended to the values already stored under the same key.
+pub trait GuitarCourseBuilderExt {
+    /// Adds the metadata which marks the course as a music course for the guitar.
+    fn with_instrument_metadata(self) -> Self;
+
+    /// Adds the fretboard to the musical skills practiced in the course.
+    fn with_fretboard_skill_metadata(self) -> Self;
+
+    /// Adds the given musical skills to the ones practiced in the course.
+    fn with_skill_metadata(self, skills: &[&str]) -> Self;
+
+    /// Adds the given musical concepts to the ones covered by the course.
+    fn with_concept_metadata(self, concepts: &[&str]) -> Self;
+
+    /// Adds the given physical techniques to the ones practiced in the course.
+    fn with_technique_metadata(self, techniques: &[&str]) -> Self;
+
+    /// Adds the scale on which the course is based.
+    fn with_scale_metadata(self, scale: impl Into<GuitarScale>) -> Self;
+
+    /// Adds the genres to which the course is relevant. Nothing is added if the list is empty.
+    fn with_genre_metadata(self, genres: &[Genre]) -> Self;
+
+    /// Adds the instructions of the course, which are written to the `course_instructions.md`
+    /// file.
+    fn with_standard_instruction_asset(self, content: &str) -> Self;
+
+    /// Adds the version of trane-guitar which generated the course.
+    fn with_version_metadata(self) -> Self;
+
+    /// Adds the IDs of the courses recommended after this one. Nothing is added if the list is
+    /// empty.
+    fn with_recommended_next_metadata(self, course_ids: &[Ustr]) -> Self;
+}
+
+/// Appends the values to the metadata of the course under the given key.
+fn add_metadata(course_builder: &mut CourseBuilder, key: String, values: Vec<String>) {
+    course_builder
+        .course_manifest
+        .metadata
+        .get_or_insert_with(BTreeMap::new)
+        .entry(key)
+        .or_default()
+        .extend(values);
+}
+
+/// Converts the values to owned strings.
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+impl GuitarCourseBuilderExt for CourseBuilder {
+    fn with_instrument_metadata(mut self) -> Self {
+        add_metadata(
+            &mut self,
+            TraneMetadata::Skill.to_string(),
+            vec!["music".to_string()],
+        );
+        add_metadata(
+            &mut self,
+            MusicMetadata::Instrument.to_string(),
+            vec!["guitar".to_string()],
+        );
+        self
+    }
+
+    fn with_fretboard_skill_metadata(self) -> Self {
+        self.with_skill_metadata(&["fretboard"])
+    }
+
+    fn with_skill_metadata(mut self, skills: &[&str]) -> Self {
+        add_metadata(
+            &mut self,
+            MusicMetadata::MusicalSkill.to_string(),
+            to_strings(skills),
+        );
+        self
+    }
+
+    fn with_concept_metadata(mut self, concepts: &[&str]) -> Self {
+        add_metadata(
+            &mut self,
+            MusicMetadata::MusicalConcept.to_string(),
+            to_strings(concepts),
+        );
+        self
+    }
+
+    fn with_technique_metadata(mut self, techniques: &[&str]) -> Self {
+        add_metadata(&mut self, TECHNIQUE_KEY.to_string(), to_strings(techniques));
+        self
+    }
+
+    fn with_scale_metadata(mut self, scale: impl Into<GuitarScale>) -> Self {
+        add_metadata(
+            &mut self,
+            MusicMetadata::ScaleType.to_string(),
+            vec![scale.into().to_string().to_lowercase()],
+        );
+        self
+    }
+
+    fn with_genre_metadata(mut self, genres: &[Genre]) -> Self {
+        if !genres.is_empty() {
+            add_genres(
+                self.course_manifest
+                    .metadata
+                    .get_or_insert_with(BTreeMap::new),
+                genres,
+            );
+        }
+        self
+    }
+
+    fn with_standard_instruction_asset(mut self, content: &str) -> Self {
+        self.course_manifest.course_instructions = Some(BasicAsset::MarkdownAsset {
+            path: INSTRUCTIONS_FILE.to_string(),
+        });
+        self.asset_builders.push(AssetBuilder {
+            file_name: INSTRUCTIONS_FILE.to_string(),
+            contents: content.to_string(),
+        });
+        self
+    }
+
+    fn with_version_metadata(mut self) -> Self {
+        add_metadata(
+            &mut self,
+            VERSION_KEY.to_string(),
+            vec![COURSE_VERSION.to_string()],
+        );
+        self
+    }
+
+    fn with_recommended_next_metadata(mut self, course_ids: &[Ustr]) -> Self {
+        if !course_ids.is_empty() {
+            add_metadata(
+                &mut self,
+                RECOMMENDED_NEXT_KEY.to_string(),
+                course_ids.iter().map(|id| id.to_string()).collect(),
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::{
+        course_builder::{music::MusicMetadata, CourseBuilder},
+        data::{CourseManifest, LessonManifestBuilder},
+    };
+    use ustr::Ustr;
+
+    use crate::{
+        builder_ext::GuitarCourseBuilderExt,
+        constants::COURSE_VERSION,
+        metadata::{GENRE_KEY, VERSION_KEY},
+    };
+
+    #[test]
+    fn course_metadata() {
+        let course_builder = CourseBuilder {
+            directory_name: "course".to_string(),
+            course_manifest: CourseManifest {
+                id: Ustr::from("course"),
+                name: "Course".to_string(),
+                dependencies: vec![],
+                description: None,
+                authors: None,
+                metadata: None,
+                course_material: None,
+                course_instructions: None,
+                generator_config: None,
+            },
+            asset_builders: vec![],
+            lesson_builders: vec![],
+            lesson_manifest_template: LessonManifestBuilder::default(),
+        }
+        .with_instrument_metadata()
+        .with_fretboard_skill_metadata()
+        .with_skill_metadata(&["harmony"])
+        .with_genre_metadata(&[])
+        .with_standard_instruction_asset("Instructions")
+        .with_version_metadata();
+
+        let metadata = course_builder.course_manifest.metadata.unwrap_or_default();
+        assert_eq!(
+            metadata.get(&MusicMetadata::MusicalSkill.to_string()),
+            Some(&vec!["fretboard".to_string(), "harmony".to_string()])
+        );
+        assert!(!metadata.contains_key(GENRE_KEY));
+        assert_eq!(
+            metadata.get(VERSION_KEY),
+            Some(&vec![COURSE_VERSION.to_string()])
+        );
+        assert!(course_builder.course_manifest.course_instructions.is_some());
+        assert_eq!(course_builder.asset_builders[0].contents, "Instructions");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod chord_melody;
+pub mod extended_voicings;
+pub mod slash_chords;
+pub mod suspended_chords;
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::{note_to_fret, semitones_up},
+    theory::voice_leading,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::chords::chord_melody");
+}
+
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Advanced),
+    backing_track_url: None,
+};
+
+/// The top four strings of the guitar from highest to lowest, given as their number and open note.
+/// The melody is always played on the first string.
+const VOICING_STRINGS: [(usize, Note); 4] =
+    [(1, Note::E), (2, Note::B), (3, Note::G), (4, Note::D)];
+
+/// The degrees of the tonic chord which are played as the melody, along with the name used in the
+/// IDs of the exercises.
+const MELODY_DEGREES: [(usize, &str); 4] = [(0, "root"), (1, "3rd"), (2, "5th"), (3, "7th")];
+
+/// Returns the ID of the lesson for the given key.
+fn lesson_id(key: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()))
+}
+
+/// Returns the fret of the note in the given string which is closest to the target fret.
+fn closest_fret(open_note: Note, note: Note, target: u8) -> u8 {
+    note_to_fret(open_note, note)
+        .into_iter()
+        .min_by_key(|fret| (*fret as i8 - target as i8).abs())
+        .unwrap()
+}
+
+/// Returns a drop 2 voicing of the chord with the note at the given index of the chord on top.
+/// The voicing is returned as the notes and frets of the top four strings, from highest to lowest.
+fn drop_two_voicing(chord: &[Note], melody_index: usize) -> Vec<(usize, Note, u8)> {
+    // In a close voicing, the chord tones below the melody descend through the chord. The drop 2
+    // voicing lowers the second voice from the top by an octave, which makes it the lowest voice.
+    let below = |steps: usize| chord[(melody_index + chord.len() - steps) % chord.len()];
+    let voices = [chord[melody_index], below(2), below(3), below(1)];
+
+    // Keep the melody between the 3rd and 14th frets so that the rest of the voicing fits below it.
+    let mut melody_fret = semitones_up(VOICING_STRINGS[0].1, voices[0]);
+    if melody_fret < 3 {
+        melody_fret += 12;
+    }
+    VOICING_STRINGS
+        .iter()
+        .zip(voices.iter())
+        .map(|((string_number, open_note), note)| {
+            (
+                *string_number,
+                *note,
+                closest_fret(*open_note, *note, melody_fret),
+            )
+        })
+        .collect()
+}
+
+/// Generates the exercise builders for the lesson of the given key. There's one exercise for each
+/// note of the tonic chord played as the melody.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale = ScaleType::Major.notes(key)?.notes;
+    let chord = vec![scale[0], scale[2], scale[4], scale[6]];
+    let chord_name = format!("{}maj7", key.to_string());
+    let chord_notes = chord
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    Ok(MELODY_DEGREES
+        .iter()
+        .map(|(melody_index, degree_name)| {
+            let degree_name = *degree_name;
+            let melody_note = chord[*melody_index];
+            let voicing = drop_two_voicing(&chord, *melody_index)
+                .iter()
+                .map(|(string_number, note, fret)| {
+                    format!(
+                        "- String {}: {} (fret {})",
+                        string_number,
+                        note.to_string(),
+                        fret
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            ExerciseBuilder {
+                directory_name: degree_name.to_string(),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play {} as a melody note on the first string. Sustain it as the top
+                            note of a {} chord, with the rest of the chord played on the three
+                            strings below it.
+                        ", melody_note.to_string(), chord_name},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The notes of the {} chord are: {}. The melody note {} is the {} of
+                            the chord.
+
+                            One possible voicing with the melody on top is:
+
+                            {}
+                        ", chord_name, chord_notes, melody_note.to_string(), degree_name, voicing},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", lesson_id, degree_name))
+                        .name(format!(
+                            "Harmonize {} with the {} of the tonic chord in {} Major",
+                            melody_note.to_string(),
+                            degree_name,
+                            key.to_string()
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        let dependencies = key
+            .previous_key_in_circle()
+            .map(lesson_id)
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = lesson_id(key);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata =
+                    BTreeMap::from([(MusicMetadata::Key.to_string(), vec![key.to_ascii_string()])]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!("Chord Melody in {} Major", key.to_string()))
+                    .description(Some(format!(
+                        "Harmonize the notes of the tonic chord of {} major.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "chord_melody".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Chord Melody".to_string(),
+            dependencies: vec![*voice_leading::COURSE_ID],
+            description: Some(
+                "Learn to harmonize a melody by playing each note as the top of a chord."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chords"])
+    .with_genre_metadata(&[Genre::Jazz])
+    .with_standard_instruction_asset(indoc! {"
+        Chord melody is the art of playing the melody and the harmony of a song at the
+        same time. Each note of the melody is played on the highest string, and the
+        rest of the chord is played on the strings below it.
+
+        The melody should always be the loudest voice. Let it ring while the chord is
+        sustained, and make sure it can be heard above the other notes.
+
+        The suggested voicings are drop 2 voicings, in which the second voice from the
+        top of a close voicing is moved down by an octave. Other voicings are possible,
+        so feel free to find your own.
+    "}))
+}
@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::semitones_up,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::chords::extended_voicings");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: None,
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// A moveable voicing of a chord.
+struct Voicing {
+    /// The string in which the root of the chord is played.
+    root_string: usize,
+
+    /// The fret of each string relative to the fret of the root, from the sixth string to the
+    /// first. Muted strings have no fret.
+    offsets: [Option<i8>; 6],
+
+    /// The finger used in each string, from the sixth string to the first, where the index finger
+    /// is 1 and the little finger is 4.
+    fingers: [Option<u8>; 6],
+}
+
+/// A type of chord along with the voicings used to play it.
+struct ChordQuality {
+    /// The ID of the chord type used in the IDs and directory names.
+    id: &'static str,
+
+    /// The suffix added to the root to form the name of the chord.
+    suffix: &'static str,
+
+    /// The full name of the chord type.
+    name: &'static str,
+
+    /// The intervals of the chord tones from the root, in semitones.
+    intervals: &'static [u8],
+
+    /// The voicings of the chord, with the root in the sixth and fifth strings.
+    voicings: [Voicing; 2],
+}
+
+/// The chord types covered in each lesson.
+const CHORD_QUALITIES: [ChordQuality; 7] = [
+    ChordQuality {
+        id: "maj7",
+        suffix: "maj7",
+        name: "major seventh",
+        intervals: &[0, 4, 7, 11],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), None, Some(1), Some(1), Some(0), None],
+                fingers: [Some(1), None, Some(3), Some(4), Some(2), None],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(1), Some(2), Some(0)],
+                fingers: [None, Some(1), Some(3), Some(2), Some(4), Some(1)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "dom7",
+        suffix: "7",
+        name: "dominant seventh",
+        intervals: &[0, 4, 7, 10],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(0), Some(1), Some(0), Some(0)],
+                fingers: [Some(1), Some(3), Some(1), Some(2), Some(1), Some(1)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(0), Some(2), Some(0)],
+                fingers: [None, Some(1), Some(3), Some(1), Some(4), Some(1)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "min7",
+        suffix: "m7",
+        name: "minor seventh",
+        intervals: &[0, 3, 7, 10],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(0), Some(0), Some(0), Some(0)],
+                fingers: [Some(1), Some(3), Some(1), Some(1), Some(1), Some(1)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(0), Some(1), Some(0)],
+                fingers: [None, Some(1), Some(3), Some(1), Some(2), Some(1)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "sus2",
+        suffix: "sus2",
+        name: "suspended second",
+        intervals: &[0, 2, 7],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(4), Some(4), Some(0), Some(0)],
+                fingers: [Some(1), Some(2), Some(3), Some(4), Some(1), Some(1)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(2), Some(0), Some(0)],
+                fingers: [None, Some(1), Some(3), Some(4), Some(1), Some(1)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "sus4",
+        suffix: "sus4",
+        name: "suspended fourth",
+        intervals: &[0, 5, 7],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(2), Some(2), Some(0), Some(0)],
+                fingers: [Some(1), Some(2), Some(3), Some(4), Some(1), Some(1)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(2), Some(3), Some(0)],
+                fingers: [None, Some(1), Some(2), Some(3), Some(4), Some(1)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "dom9",
+        suffix: "9",
+        name: "dominant ninth",
+        intervals: &[0, 2, 4, 7, 10],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(0), Some(1), Some(0), Some(2)],
+                fingers: [Some(1), Some(3), Some(1), Some(2), Some(1), Some(4)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(-1), Some(0), Some(0), Some(0)],
+                fingers: [None, Some(2), Some(1), Some(3), Some(3), Some(3)],
+            },
+        ],
+    },
+    ChordQuality {
+        id: "add9",
+        suffix: "add9",
+        name: "added ninth",
+        intervals: &[0, 2, 4, 7],
+        voicings: [
+            Voicing {
+                root_string: 6,
+                offsets: [Some(0), Some(2), Some(4), Some(1), Some(0), Some(0)],
+                fingers: [Some(1), Some(3), Some(4), Some(2), Some(1), Some(1)],
+            },
+            Voicing {
+                root_string: 5,
+                offsets: [None, Some(0), Some(2), Some(4), Some(2), Some(0)],
+                fingers: [None, Some(1), Some(2), Some(4), Some(3), Some(1)],
+            },
+        ],
+    },
+];
+
+impl ChordQuality {
+    /// Returns the chord tones as degrees counted from the root (e.g., "1, 3, 5, ♭7").
+    fn formula(&self) -> String {
+        self.intervals
+            .iter()
+            .map(|interval| match interval {
+                0 => "1",
+                2 => "9",
+                3 => "♭3",
+                4 => "3",
+                5 => "4",
+                7 => "5",
+                10 => "♭7",
+                11 => "7",
+                _ => "?",
+            })
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+}
+
+impl Voicing {
+    /// Returns the fret of each string when the voicing is played with the given root. The root is
+    /// moved up an octave if the voicing would otherwise need frets below the nut.
+    fn frets(&self, root: Note) -> [Option<u8>; 6] {
+        let lowest_offset = self.offsets.iter().flatten().min().copied().unwrap_or(0);
+        let mut root_fret =
+            semitones_up(tunings::STANDARD[6 - self.root_string].open_note, root) as i8;
+        if root_fret + lowest_offset < 1 {
+            root_fret += 12;
+        }
+        self.offsets
+            .map(|offset| offset.map(|offset| (root_fret + offset) as u8))
+    }
+
+    /// Returns a description of the voicing with the given root.
+    fn describe(&self, root: Note) -> String {
+        let frets = self
+            .frets(root)
+            .iter()
+            .map(|fret| fret.map_or("x".to_string(), |fret| fret.to_string()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let fingers = self
+            .fingers
+            .iter()
+            .map(|finger| finger.map_or("x".to_string(), |finger| finger.to_string()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        formatdoc! {"
+            - Root on string {}:
+              - Frets (strings 6 to 1): {}
+              - Fingers (strings 6 to 1): {}
+        ", self.root_string, frets, fingers}
+    }
+}
+
+/// Returns a description of the voicing of the dominant seventh chord with the given root and with
+/// the root in the given string. Only the sixth and fifth strings have a voicing.
+pub fn dominant_seventh_voicing(root: Note, root_string: usize) -> Option<String> {
+    CHORD_QUALITIES
+        .iter()
+        .find(|quality| quality.id == "dom7")?
+        .voicings
+        .iter()
+        .find(|voicing| voicing.root_string == root_string)
+        .map(|voicing| voicing.describe(root))
+}
+
+/// Returns the descriptions of all the voicings of the chord type with the given ID and root.
+pub fn chord_voicings(chord_id: &str, root: Note) -> Vec<String> {
+    CHORD_QUALITIES
+        .iter()
+        .find(|quality| quality.id == chord_id)
+        .map(|quality| {
+            quality
+                .voicings
+                .iter()
+                .map(|voicing| voicing.describe(root))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Generates the exercise builders for the lesson of the given root. There's one exercise for each
+/// chord type.
+fn generate_exercise_builders(lesson_id: Ustr, root: Note) -> Vec<ExerciseBuilder> {
+    CHORD_QUALITIES
+        .iter()
+        .map(|quality| {
+            let chord_id = quality.id;
+            let chord_name = format!("{}{}", root.to_string(), quality.suffix);
+            let voicings = quality
+                .voicings
+                .iter()
+                .map(|voicing| voicing.describe(root))
+                .collect::<String>();
+
+            ExerciseBuilder {
+                directory_name: chord_id.to_string(),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} chord ({} {}) with the root on the sixth string and then
+                            with the root on the fifth string.
+                        ", chord_name, root.to_string(), quality.name},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The {} chord is formed by the degrees {}, counted from {}. It can be
+                            played with the following voicings:
+
+                            {}
+                            The fingers are numbered from the index finger (1) to the little
+                            finger (4). Strings marked with an x are muted.
+                        ", chord_name, quality.formula(), root.to_string(), voicings},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", lesson_id, chord_id))
+                        .name(format!("Play the {} chord", chord_name))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let mut lesson_builders = vec![];
+    for root in Note::all_keys(false) {
+        let dependencies = root
+            .previous_key_in_circle()
+            .map(|previous_root| {
+                Ustr::from(&format!("{}::{}", *COURSE_ID, previous_root.to_string()))
+            })
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, root.to_string()));
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", root.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, root),
+            manifest_closure: Box::new(move |m| {
+                let mut metadata = BTreeMap::from([(
+                    MusicMetadata::Key.to_string(),
+                    vec![root.to_ascii_string()],
+                )]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Extended Voicings with {} as the Root",
+                        root.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play seventh, suspended, and ninth chords with {} as the root.",
+                        root.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    CourseBuilder {
+        directory_name: "extended_voicings".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Extended Chord Voicings".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Learn moveable voicings of seventh, suspended, and ninth chords.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chords"])
+    .with_genre_metadata(&[Genre::Jazz, Genre::Pop])
+    .with_standard_instruction_asset(indoc! {"
+        The voicings in this course are moveable shapes based on the barre chords with
+        the root on the sixth and fifth strings. Once a shape is learned, it can be
+        moved to any root by finding the root in the lowest string of the chord.
+
+        Play each chord one string at a time to check that every note rings clearly,
+        then strum it. Make sure the muted strings don't sound.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::notes::Note;
+
+    use crate::{
+        chords::extended_voicings::CHORD_QUALITIES,
+        music::{semitones, semitones_up},
+        tunings,
+    };
+
+    /// Verifies that every voicing only contains notes of the chord and includes its root.
+    #[test]
+    fn voicing_notes() {
+        for root in Note::all_keys(false) {
+            for quality in &CHORD_QUALITIES {
+                for voicing in &quality.voicings {
+                    let intervals = voicing
+                        .frets(root)
+                        .iter()
+                        .zip(tunings::STANDARD.iter())
+                        .filter_map(|(fret, guitar_string)| {
+                            fret.map(|fret| (semitones(guitar_string.open_note) + fret) % 12)
+                        })
+                        .map(|pitch| (pitch + 12 - semitones(root)) % 12)
+                        .collect::<Vec<u8>>();
+                    assert!(intervals.contains(&0));
+                    assert!(intervals
+                        .iter()
+                        .all(|interval| quality.intervals.contains(interval)));
+                    assert_eq!(
+                        semitones_up(tunings::STANDARD[6 - voicing.root_string].open_note, root)
+                            % 12,
+                        (voicing.frets(root)[6 - voicing.root_string].unwrap()) % 12
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    chords::extended_voicings,
+    constants::AUTHORS,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::semitones_up,
+    theory::voice_leading,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::chords::slash");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: None,
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// A moveable voicing of a major chord with its 3rd in the bass.
+struct SlashVoicing {
+    /// The string in which the bass note is played. The strings below it are muted, so the bass
+    /// note is always the lowest-sounding note.
+    bass_string: usize,
+
+    /// The fret of each string relative to the fret of the bass note, from the sixth string to the
+    /// first. Muted strings have no fret.
+    offsets: [Option<u8>; 6],
+
+    /// The finger used in each string when the voicing is played above the open position, from the
+    /// sixth string to the first, where the index finger is 1 and the little finger is 4.
+    fingers: [Option<u8>; 6],
+}
+
+/// The voicings covered in each lesson.
+const VOICINGS: [SlashVoicing; 2] = [
+    SlashVoicing {
+        bass_string: 6,
+        offsets: [Some(0), None, Some(2), Some(0), Some(1), None],
+        fingers: [Some(2), None, Some(4), Some(1), Some(3), None],
+    },
+    SlashVoicing {
+        bass_string: 5,
+        offsets: [None, Some(0), Some(3), Some(2), Some(1), None],
+        fingers: [None, Some(1), Some(4), Some(3), Some(2), None],
+    },
+];
+
+impl SlashVoicing {
+    /// Returns the fret of each string when the voicing is played with the given bass note.
+    fn frets(&self, bass: Note) -> [Option<u8>; 6] {
+        let bass_fret = semitones_up(tunings::STANDARD[6 - self.bass_string].open_note, bass);
+        self.offsets
+            .map(|offset| offset.map(|offset| bass_fret + offset))
+    }
+
+    /// Returns the finger used in each string when the voicing is played with the given bass note.
+    /// Strings played open are marked with a 0, since no finger frets them, and the fingers of the
+    /// remaining strings are shifted so that the index finger plays the lowest fretted note.
+    fn fingers(&self, bass: Note) -> [Option<u8>; 6] {
+        let frets = self.frets(bass);
+        let mut fingers = self.fingers;
+        for (finger, fret) in fingers.iter_mut().zip(frets.iter()) {
+            if *fret == Some(0) {
+                *finger = Some(0);
+            }
+        }
+        let lowest_finger = fingers
+            .iter()
+            .flatten()
+            .filter(|finger| **finger > 0)
+            .min()
+            .copied()
+            .unwrap_or(1);
+        fingers.map(|finger| {
+            finger.map(|finger| {
+                if finger > 0 {
+                    finger - lowest_finger + 1
+                } else {
+                    0
+                }
+            })
+        })
+    }
+
+    /// Returns a description of the voicing with the given bass note.
+    fn describe(&self, bass: Note) -> String {
+        let frets = self
+            .frets(bass)
+            .iter()
+            .map(|fret| fret.map_or("x".to_string(), |fret| fret.to_string()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let fingers = self
+            .fingers(bass)
+            .iter()
+            .map(|finger| finger.map_or("x".to_string(), |finger| finger.to_string()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        formatdoc! {"
+            - Frets (strings 6 to 1): {}
+            - Fingers (strings 6 to 1): {}
+        ", frets, fingers}
+    }
+}
+
+/// Generates the exercise builders for the lesson of the major chord with the given root over its
+/// 3rd. There's one exercise for each voicing.
+fn generate_exercise_builders(lesson_id: Ustr, root: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(root)?.notes;
+    let (third, fifth) = (scale_notes[2], scale_notes[4]);
+    let chord_name = format!("{}/{}", root.to_string(), third.to_string());
+
+    Ok(VOICINGS
+        .iter()
+        .map(|voicing| {
+            let bass_string = voicing.bass_string;
+            let chord_name = chord_name.clone();
+            ExerciseBuilder {
+                directory_name: format!("bass_string_{}", bass_string),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} chord with the bass note on string {}.
+                        ", chord_name, bass_string},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The {} chord is the {} major chord ({}, {}, {}) with its 3rd, {}, in
+                            the bass. It can be played with the following voicing:
+
+                            {}
+                            Mute the strings marked with an x so that {} is the lowest-sounding
+                            note.
+                        ",
+                        chord_name,
+                        root.to_string(),
+                        root.to_string(),
+                        third.to_string(),
+                        fifth.to_string(),
+                        third.to_string(),
+                        voicing.describe(third),
+                        third.to_string()},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::bass_string_{}", lesson_id, bass_string))
+                        .name(format!(
+                            "Play the {} chord with the bass on string {}",
+                            chord_name, bass_string
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for root in Note::all_keys(false) {
+        let dependencies = root
+            .previous_key_in_circle()
+            .map(|previous_root| {
+                Ustr::from(&format!("{}::{}", *COURSE_ID, previous_root.to_string()))
+            })
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, root.to_string()));
+        let third = ScaleType::Major.notes(root)?.notes[2];
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", root.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, root)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata = BTreeMap::from([(
+                    MusicMetadata::Key.to_string(),
+                    vec![root.to_ascii_string()],
+                )]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "The {}/{} Slash Chord",
+                        root.to_string(),
+                        third.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} major chord with its 3rd in the bass.",
+                        root.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "slash_chords".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Slash Chords".to_string(),
+            dependencies: vec![*extended_voicings::COURSE_ID, *voice_leading::COURSE_ID],
+            description: Some(
+                "Play major chords with their 3rd in the bass, such as G/B or D/F♯, in all keys."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chords", "voice leading"])
+    .with_genre_metadata(&[Genre::Pop, Genre::Rock])
+    .with_standard_instruction_asset(indoc! {"
+        A slash chord names a chord and the note played in its bass, as in G/B, which
+        is a G major chord with B in the bass. The most common slash chords, such as
+        G/B, D/F♯, C/E, A/C♯, E/G♯, and F/A, put the 3rd of a major chord in the bass.
+
+        They are used to make the bass line move by step between chords, as in C -
+        G/B - Am. Make sure the strings below the bass note don't sound, so that the
+        bass note is always the lowest one.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::{notes::Note, scales::ScaleType};
+
+    use crate::{
+        chords::slash_chords::{course_builder, VOICINGS},
+        in_memory::build_course_in_memory,
+        music::semitones,
+        tunings,
+    };
+
+    /// Verifies that every voicing only contains notes of the chord, that the bass note is the
+    /// lowest-sounding note, and that only the fretted strings are assigned a finger.
+    #[test]
+    fn voicing_notes() -> anyhow::Result<()> {
+        for root in Note::all_keys(false) {
+            let scale_notes = ScaleType::Major.notes(root)?.notes;
+            let chord_pitches = [0, 2, 4].map(|degree| semitones(scale_notes[degree]));
+            for voicing in &VOICINGS {
+                let frets = voicing.frets(scale_notes[2]);
+                let pitches = frets
+                    .iter()
+                    .zip(tunings::STANDARD.iter())
+                    .filter_map(|(fret, guitar_string)| {
+                        fret.map(|fret| (semitones(guitar_string.open_note) + fret) % 12)
+                    })
+                    .collect::<Vec<u8>>();
+                assert_eq!(pitches[0], semitones(scale_notes[2]));
+                assert!(pitches.iter().all(|pitch| chord_pitches.contains(pitch)));
+                assert!(frets[..6 - voicing.bass_string]
+                    .iter()
+                    .all(|fret| fret.is_none()));
+                for (fret, finger) in frets.iter().zip(voicing.fingers(scale_notes[2]).iter()) {
+                    match fret {
+                        None => assert_eq!(*finger, None),
+                        Some(0) => assert_eq!(*finger, Some(0)),
+                        Some(_) => assert!(matches!(finger, Some(1..=4))),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that the course contains a lesson for each key, with an exercise for each voicing.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 24);
+        Ok(())
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    chords::extended_voicings::{self, chord_voicings},
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::chords::suspended");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: None,
+    difficulty: Some(Difficulty::Beginner),
+    backing_track_url: None,
+};
+
+/// A type of suspended chord.
+struct Suspension {
+    /// The ID of the chord type, which matches the ID used in the extended voicings course.
+    id: &'static str,
+
+    /// The full name of the chord type.
+    name: &'static str,
+
+    /// The index in the major scale of the degree which replaces the 3rd, used to spell it.
+    scale_index: usize,
+
+    /// The name of the degree which replaces the 3rd.
+    degree: &'static str,
+}
+
+/// The suspended chords covered in each lesson.
+const SUSPENSIONS: [Suspension; 2] = [
+    Suspension {
+        id: "sus2",
+        name: "suspended second",
+        scale_index: 1,
+        degree: "2nd",
+    },
+    Suspension {
+        id: "sus4",
+        name: "suspended fourth",
+        scale_index: 3,
+        degree: "4th",
+    },
+];
+
+/// The open shapes of the suspended chords, given as the root, the ID of the chord type, and the
+/// fret of each string from the sixth string to the first. Muted strings have no fret. Only the
+/// roots with a common open shape are included.
+const OPEN_SHAPES: [(Note, &str, [Option<u8>; 6]); 10] = [
+    (
+        Note::C,
+        "sus2",
+        [None, Some(3), Some(0), Some(0), Some(1), None],
+    ),
+    (
+        Note::C,
+        "sus4",
+        [None, Some(3), Some(3), Some(0), Some(1), Some(1)],
+    ),
+    (
+        Note::G,
+        "sus2",
+        [Some(3), Some(0), Some(0), Some(0), Some(3), Some(3)],
+    ),
+    (
+        Note::G,
+        "sus4",
+        [Some(3), Some(3), Some(0), Some(0), Some(1), Some(3)],
+    ),
+    (
+        Note::D,
+        "sus2",
+        [None, None, Some(0), Some(2), Some(3), Some(0)],
+    ),
+    (
+        Note::D,
+        "sus4",
+        [None, None, Some(0), Some(2), Some(3), Some(3)],
+    ),
+    (
+        Note::A,
+        "sus2",
+        [None, Some(0), Some(2), Some(2), Some(0), Some(0)],
+    ),
+    (
+        Note::A,
+        "sus4",
+        [None, Some(0), Some(2), Some(2), Some(3), Some(0)],
+    ),
+    (
+        Note::E,
+        "sus2",
+        [Some(0), Some(2), Some(4), Some(4), Some(0), Some(0)],
+    ),
+    (
+        Note::E,
+        "sus4",
+        [Some(0), Some(2), Some(2), Some(2), Some(0), Some(0)],
+    ),
+];
+
+/// Returns the description of the open shape of the chord with the given root and type, if any.
+fn open_shape(root: Note, chord_id: &str) -> Option<String> {
+    OPEN_SHAPES
+        .iter()
+        .find(|(shape_root, shape_id, _)| *shape_root == root && *shape_id == chord_id)
+        .map(|(_, _, frets)| {
+            let frets = frets
+                .iter()
+                .map(|fret| fret.map_or("x".to_string(), |fret| fret.to_string()))
+                .collect::<Vec<String>>()
+                .join(" ");
+            formatdoc! {"
+                - Open shape:
+                  - Frets (strings 6 to 1): {}
+            ", frets}
+        })
+}
+
+/// Returns the exercise builder for the given chord with the given voicings.
+fn exercise_builder(
+    lesson_id: Ustr,
+    root: Note,
+    suspension: &'static Suspension,
+    shape: &'static str,
+    voicings: String,
+) -> Result<ExerciseBuilder> {
+    let chord_name = format!("{}{}", root.to_string(), suspension.id);
+    // The notes are spelled as the degrees of the major scale with the same root.
+    let scale_notes = ScaleType::Major.notes(root)?.notes;
+    let suspended_note = scale_notes[suspension.scale_index];
+    let fifth = scale_notes[4];
+    let exercise_id = format!("{}_{}", suspension.id, shape);
+
+    Ok(ExerciseBuilder {
+        directory_name: exercise_id.clone(),
+        asset_builders: vec![
+            AssetBuilder {
+                file_name: "front.md".to_string(),
+                contents: formatdoc! {"
+                    Play the {} chord ({} {}) using {} shape.
+                ",
+                chord_name,
+                root.to_string(),
+                suspension.name,
+                if shape == "open" { "its open" } else { "a barre" }},
+            },
+            AssetBuilder {
+                file_name: "back.md".to_string(),
+                contents: formatdoc! {"
+                    The {} chord replaces the 3rd with the {}. Its notes are {}, {}, and {}.
+
+                    It can be played with the following voicings:
+
+                    {}
+                    The fingers are numbered from the index finger (1) to the little finger
+                    (4). Strings marked with an x are muted.
+                ",
+                chord_name,
+                suspension.degree,
+                root.to_string(),
+                suspended_note.to_string(),
+                fifth.to_string(),
+                voicings},
+            },
+        ],
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(format!("{}::{}", lesson_id, exercise_id))
+                .name(format!("Play the {} chord ({} shape)", chord_name, shape))
+                .clone()
+        }),
+    })
+}
+
+/// Generates the exercise builders for the lesson of the given root. There's an exercise for the
+/// barre shapes of each suspended chord, and another for its open shape if the root has one.
+fn generate_exercise_builders(lesson_id: Ustr, root: Note) -> Result<Vec<ExerciseBuilder>> {
+    let mut builders = vec![];
+    for suspension in &SUSPENSIONS {
+        let barre_voicings = chord_voicings(suspension.id, root);
+        if let Some(open_voicing) = open_shape(root, suspension.id) {
+            // The first barre voicing is included so that the open shape can be compared with it.
+            builders.push(exercise_builder(
+                lesson_id,
+                root,
+                suspension,
+                "open",
+                format!("{}{}", open_voicing, barre_voicings[0]),
+            )?);
+        }
+        builders.push(exercise_builder(
+            lesson_id,
+            root,
+            suspension,
+            "barre",
+            barre_voicings.concat(),
+        )?);
+    }
+    Ok(builders)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for root in Note::all_keys(false) {
+        let dependencies = root
+            .previous_key_in_circle()
+            .map(|previous_root| {
+                Ustr::from(&format!("{}::{}", *COURSE_ID, previous_root.to_string()))
+            })
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, root.to_string()));
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", root.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, root)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata = BTreeMap::from([(
+                    MusicMetadata::Key.to_string(),
+                    vec![root.to_ascii_string()],
+                )]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Suspended Chords with {} as the Root",
+                        root.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the sus2 and sus4 chords with {} as the root.",
+                        root.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "suspended_chords".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Suspended Chords".to_string(),
+            dependencies: vec![
+                *basic_guitar_fretboard::COURSE_ID,
+                *extended_voicings::COURSE_ID,
+            ],
+            description: Some(
+                "Learn the open and barre shapes of the sus2 and sus4 chords in all keys."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chords"])
+    .with_genre_metadata(&[Genre::Pop, Genre::Rock])
+    .with_standard_instruction_asset(indoc! {"
+        Suspended chords replace the 3rd of a major or minor chord with the 2nd or the
+        4th. Without a 3rd, they are neither major nor minor, which gives them their
+        open and unresolved sound.
+
+        Practice moving between each suspended chord and the major chord with the same
+        root, which is how they are most often used in pop and rock songs.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::scales::ScaleType;
+
+    use crate::{
+        chords::suspended_chords::{course_builder, OPEN_SHAPES, SUSPENSIONS},
+        in_memory::build_course_in_memory,
+        music::semitones,
+        tunings,
+    };
+
+    /// Verifies that every open shape only contains notes of the chord.
+    #[test]
+    fn open_shape_notes() -> anyhow::Result<()> {
+        for (root, chord_id, frets) in &OPEN_SHAPES {
+            let suspension = SUSPENSIONS
+                .iter()
+                .find(|suspension| suspension.id == *chord_id)
+                .unwrap();
+            let scale_notes = ScaleType::Major.notes(*root)?.notes;
+            let chord_pitches = [
+                semitones(*root),
+                semitones(scale_notes[suspension.scale_index]),
+                semitones(scale_notes[4]),
+            ];
+            for (fret, guitar_string) in frets.iter().zip(tunings::STANDARD.iter()) {
+                if let Some(fret) = fret {
+                    let pitch = semitones(guitar_string.open_note) + fret;
+                    assert!(chord_pitches.contains(&(pitch % 12)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that the course contains a lesson for each key, with an open shape for each
+    /// suspension only in the keys that have one.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 34);
+        Ok(())
+    }
+}
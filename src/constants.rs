@@ -0,0 +1,8 @@
+//! Constants shared by all the courses.
+
+/// The authors of the courses.
+pub const AUTHORS: &str = "The Trane Project";
+
+/// The version of trane-guitar used to generate the courses. It's stored in the metadata of each
+/// course so that users can tell which version generated their library.
+pub const COURSE_VERSION: &str = env!("CARGO_PKG_VERSION");
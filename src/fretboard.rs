@@ -1,8 +1,30 @@
 pub mod advanced_guitar_fretboard;
 pub mod basic_guitar_fretboard;
-mod fretboard_exploration;
+pub mod chromatic_scale;
+pub mod cross_string_sequences;
+pub mod dadgad;
+pub mod double_harmonic_scale;
+pub mod fretboard_exploration;
+pub mod full_step_down_major;
+pub mod geography_quiz;
+pub mod guitar_string;
+pub mod half_step_down_major;
+pub mod lesson_factory;
+pub mod major_pentatonic_scale;
 pub mod major_scale;
+pub mod minor_pentatonic_scale;
 pub mod minor_scale;
+pub mod mixolydian_blues;
+pub mod modes;
+pub mod note_aliases;
+pub mod note_position_quiz;
+pub mod open_e;
+pub mod open_g;
+pub mod position_shifts;
+pub mod positions;
+pub mod scale_sequences;
+pub mod symmetric_diminished;
+pub mod whole_neck_scale;
 
 use indoc::{formatdoc, indoc};
 use trane::{course_builder::AssetBuilder, data::music::notes::*};
@@ -1,19 +1,19 @@
-use std::collections::BTreeMap;
-
 use indoc::indoc;
 use lazy_static::lazy_static;
 use trane::{
-    course_builder::{
-        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
-        TraneMetadata,
-    },
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
     data::{
         CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType, LessonManifestBuilder,
     },
 };
 use ustr::Ustr;
 
-use crate::{fretboard::basic_guitar_fretboard, AUTHORS};
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    metadata::{Difficulty, GuitarExerciseMetadata},
+};
 
 lazy_static! {
     pub static ref COURSE_ID: Ustr = Ustr::from("trane::music::guitar::advanced_fretboard");
@@ -27,6 +27,13 @@ lazy_static! {
         Ustr::from("trane::music::guitar::advanced_fretboard::lesson_4");
 }
 
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: None,
+    difficulty: Some(Difficulty::Advanced),
+    backing_track_url: None,
+};
+
 pub fn course_builder() -> CourseBuilder {
     let lesson1: LessonBuilder = LessonBuilder {
         directory_name: "lesson_1".to_string(),
@@ -45,6 +52,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON1_ID)
                 .dependencies(vec![])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 1".to_string())
                 .description(Some(
                     "Find the location of two random notes in the fretboard".to_string(),
@@ -104,6 +112,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON2_ID)
                 .dependencies(vec![*LESSON1_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 2".to_string())
                 .description(Some(
                     "Find the location of seven random notes in the fretboard".to_string(),
@@ -163,6 +172,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON3_ID)
                 .dependencies(vec![*LESSON2_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 3".to_string())
                 .description(Some(
                     "Find the location of two random notes in the fretboard".to_string(),
@@ -223,6 +233,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON4_ID)
                 .dependencies(vec![*LESSON3_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 4".to_string())
                 .description(Some(
                     "Find the location of seven random notes in the fretboard".to_string(),
@@ -274,17 +285,7 @@ pub fn course_builder() -> CourseBuilder {
             dependencies: vec![*basic_guitar_fretboard::LESSON3_ID],
             description: Some("Learn the position of notes in the guitar frateboard".to_string()),
             authors: Some(vec![AUTHORS.to_string()]),
-            metadata: Some(BTreeMap::from([
-                (TraneMetadata::Skill.to_string(), vec!["music".to_string()]),
-                (
-                    MusicMetadata::Instrument.to_string(),
-                    vec!["guitar".to_string()],
-                ),
-                (
-                    MusicMetadata::MusicalSkill.to_string(),
-                    vec!["fretboard".to_string()],
-                ),
-            ])),
+            metadata: None,
             course_material: None,
             course_instructions: None,
             generator_config: None,
@@ -295,4 +296,6 @@ pub fn course_builder() -> CourseBuilder {
             .course_id(*COURSE_ID)
             .clone(),
     }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
 }
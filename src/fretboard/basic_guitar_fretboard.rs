@@ -1,12 +1,7 @@
-use std::collections::BTreeMap;
-
 use indoc::indoc;
 use lazy_static::lazy_static;
 use trane::{
-    course_builder::{
-        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
-        TraneMetadata,
-    },
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
     data::{
         music::notes::*, BasicAsset, CourseManifest, ExerciseAsset, ExerciseManifestBuilder,
         ExerciseType, LessonManifestBuilder,
@@ -14,7 +9,13 @@ use trane::{
 };
 use ustr::Ustr;
 
-use crate::{fretboard::generate_asset_builders, AUTHORS};
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::generate_asset_builders,
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    theory::basic_theory,
+};
 
 lazy_static! {
     pub static ref COURSE_ID: Ustr = Ustr::from("trane::music::guitar::basic_fretboard");
@@ -24,6 +25,13 @@ lazy_static! {
     pub static ref LESSON4_ID: Ustr = Ustr::from("trane::music::guitar::basic_fretboard::lesson_4");
 }
 
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: None,
+    difficulty: Some(Difficulty::Beginner),
+    backing_track_url: None,
+};
+
 static LESSON1_TEMPO: &str = "at a slow tempo without a metronome";
 static LESSON2_TEMPO: &str = "at 40 bpm using a metronome";
 static LESSON3_TEMPO: &str = "at 40 bpm using a metronome";
@@ -79,6 +87,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON1_ID)
                 .dependencies(vec![])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 1".to_string())
                 .lesson_instructions(Some(BasicAsset::MarkdownAsset {
                     path: "instructions.md".to_string(),
@@ -131,6 +140,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON2_ID)
                 .dependencies(vec![*LESSON1_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 2".to_string())
                 .lesson_instructions(Some(BasicAsset::MarkdownAsset {
                     path: "instructions.md".to_string(),
@@ -184,6 +194,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(*LESSON3_ID)
                 .dependencies(vec![*LESSON2_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 3".to_string())
                 .lesson_instructions(Some(BasicAsset::MarkdownAsset {
                     path: "instructions.md".to_string(),
@@ -244,6 +255,7 @@ pub fn course_builder() -> CourseBuilder {
             m.clone()
                 .id(LESSON4_ID.to_string())
                 .dependencies(vec![*LESSON3_ID])
+                .metadata(Some(EXERCISE_METADATA.to_metadata()))
                 .name("Lesson 4".to_string())
                 .lesson_instructions(Some(BasicAsset::MarkdownAsset {
                     path: "instructions.md".to_string(),
@@ -287,20 +299,10 @@ pub fn course_builder() -> CourseBuilder {
         course_manifest: CourseManifest {
             id: *COURSE_ID,
             name: "Basic Guitar Fretboard".to_string(),
-            dependencies: vec![],
+            dependencies: vec![*basic_theory::COURSE_ID],
             description: Some("Learn the position of notes in the guitar frateboard".to_string()),
             authors: Some(vec![AUTHORS.to_string()]),
-            metadata: Some(BTreeMap::from([
-                (TraneMetadata::Skill.to_string(), vec!["music".to_string()]),
-                (
-                    MusicMetadata::Instrument.to_string(),
-                    vec!["guitar".to_string()],
-                ),
-                (
-                    MusicMetadata::MusicalSkill.to_string(),
-                    vec!["fretboard".to_string()],
-                ),
-            ])),
+            metadata: None,
             course_material: None,
             course_instructions: None,
             generator_config: None,
@@ -311,4 +313,6 @@ pub fn course_builder() -> CourseBuilder {
             .course_id(*COURSE_ID)
             .clone(),
     }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
 }
@@ -0,0 +1,169 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    music::{chromatic_note_name, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::chromatic_scale");
+}
+
+/// The frets from which the chromatic scale is played in each string, along with the name of the
+/// position.
+const START_POSITIONS: [(u8, &str); 3] = [(0, "open"), (5, "5th_fret"), (9, "9th_fret")];
+
+/// Returns a markdown table with the twelve frets of the chromatic scale starting at the given
+/// fret of the string with the given open note.
+fn fret_table(open_note: Note, start_fret: u8) -> String {
+    let rows = (start_fret..start_fret + 12)
+        .map(|fret| {
+            format!(
+                "| {} | {} |",
+                fret,
+                chromatic_note_name(semitones(open_note) + fret)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        | Fret | Note |
+        |------|------|
+        {}
+    ", rows}
+}
+
+/// Generates the exercise builders for the lesson of the given string. There's one exercise for
+/// each starting position.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    string_number: u8,
+    open_note: Note,
+) -> Vec<ExerciseBuilder> {
+    START_POSITIONS
+        .iter()
+        .map(|(start_fret, position_id)| {
+            let start_fret = *start_fret;
+            let position_id = *position_id;
+            ExerciseBuilder {
+                directory_name: position_id.to_string(),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the chromatic scale in string {} ({}) starting from fret {}, up
+                            one octave and back down.
+                        ", string_number, open_note.to_string(), start_fret},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The chromatic scale goes through the following frets:
+
+                            {}
+                            Fret {} completes the octave with the starting note.
+                        ", fret_table(open_note, start_fret), start_fret + 12},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", lesson_id, position_id))
+                        .name(format!(
+                            "Chromatic scale from fret {} of string {}",
+                            start_fret, string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let lesson_builders = tunings::STANDARD
+        .iter()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let lesson_id = Ustr::from(&format!("{}::string_{}", *COURSE_ID, string_number));
+
+            LessonBuilder {
+                directory_name: format!("lesson_string_{}", string_number),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(
+                    lesson_id,
+                    string_number,
+                    guitar_string.open_note,
+                ),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("The Chromatic Scale in String {}", string_number))
+                        .description(Some(format!(
+                            "Play the chromatic scale from three positions in string {}.",
+                            string_number
+                        )))
+                        .dependencies(vec![])
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "chromatic_scale".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "The Chromatic Scale".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Play the chromatic scale in each string to develop the control of the fretting \
+                hand."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["scales"])
+    .with_standard_instruction_asset(indoc! {"
+        The chromatic scale contains all twelve notes in the octave, so it's the same
+        for every root. Play it with one finger per fret and shift the hand to the next
+        four frets when the little finger is reached.
+
+        Keep the fingers close to the fretboard and make every note sound evenly. Say
+        the name of each note as you play it to learn its position in the string.
+    "})
+}
@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{note_aliases, scale_sequences},
+    music::{circle_fifths_keys, semitones_up},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::cross_string");
+}
+
+/// The number of strings in each of the diagonal patterns.
+const PATTERNS: [usize; 2] = [2, 3];
+
+/// The number of notes of the sequence shown in the answer.
+const NUM_ANSWER_NOTES: usize = 4;
+
+/// The lowest fret at which the tonic is played in the 6th string. Starting higher in the neck
+/// ensures the notes in the following strings can be reached, since each one is played a few frets
+/// lower than the note before it.
+const MIN_TONIC_FRET: u8 = 7;
+
+/// Returns the string and fret of the first notes of the diagonal pattern across the given number
+/// of strings. Each group plays consecutive notes of the scale, one per string starting from the
+/// 6th string, and each new group starts one note higher in the scale than the previous one.
+fn diagonal_pattern(scale_notes: &[Note], num_strings: usize) -> Vec<(u8, u8, Note)> {
+    let mut tonic_fret = semitones_up(tunings::STANDARD[0].open_note, scale_notes[0]);
+    if tonic_fret < MIN_TONIC_FRET {
+        tonic_fret += 12;
+    }
+
+    // Compute the pitch of each note of the scale above the tonic in the 6th string.
+    let mut pitches = vec![tunings::STANDARD[0].pitch() + tonic_fret];
+    for index in 1..NUM_ANSWER_NOTES + num_strings {
+        let previous_note = scale_notes[(index - 1) % scale_notes.len()];
+        let note = scale_notes[index % scale_notes.len()];
+        pitches.push(pitches[index - 1] + semitones_up(previous_note, note));
+    }
+
+    (0..)
+        .flat_map(|group| (0..num_strings).map(move |string| (group, string)))
+        .take(NUM_ANSWER_NOTES)
+        .map(|(group, string)| {
+            let degree = group + string;
+            let guitar_string = tunings::STANDARD[string];
+            (
+                guitar_string.string_number,
+                pitches[degree] - guitar_string.pitch(),
+                scale_notes[degree % scale_notes.len()],
+            )
+        })
+        .collect()
+}
+
+/// Generates the exercise builders for the lesson with the given scale and key. There's one
+/// exercise for each diagonal pattern.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    scale: ScaleType,
+    key: Note,
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = scale.notes(key)?.notes;
+    Ok(PATTERNS
+        .iter()
+        .map(|num_strings| {
+            let num_strings = *num_strings;
+            let answer = diagonal_pattern(&scale_notes, num_strings)
+                .iter()
+                .map(|(string, fret, note)| {
+                    format!("- String {}, fret {} ({})", string, fret, note.to_string())
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let lowest_string = tunings::STANDARD[0].string_number;
+            let highest_string = tunings::STANDARD[num_strings - 1].string_number;
+
+            ExerciseBuilder {
+                directory_name: format!("{}_strings", num_strings),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} {} scale in the diagonal pattern across {} strings. Play
+                            one note per string from string {} to string {}, then start again
+                            from the next note of the scale in string {}.
+                        ",
+                        key.to_string(),
+                        scale.to_string(),
+                        num_strings,
+                        lowest_string,
+                        highest_string,
+                        lowest_string},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The first {} notes of the sequence are:
+
+                            {}
+                        ", NUM_ANSWER_NOTES, answer},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}_strings", lesson_id, num_strings))
+                        .name(format!(
+                            "Diagonal pattern across {} strings on the {} {} scale",
+                            num_strings,
+                            key.to_string(),
+                            scale.to_string()
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+/// Generates a lesson for each key of the given scale. The lessons follow the circle of fifths. The
+/// note alias is used to obtain the tonic of the scale from each major key.
+fn generate_lesson_builders(
+    scale: ScaleType,
+    scale_id: &str,
+    note_alias: fn(Note) -> Result<Note>,
+) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let mut lessons = vec![];
+    for (key, previous_key) in circle_fifths_keys() {
+        let note = note_alias(key)?;
+        let previous_note = previous_key.map(note_alias).transpose()?;
+        let lesson_id = Ustr::from(&format!(
+            "{}::{}::{}",
+            course_id,
+            scale_id,
+            note.to_string()
+        ));
+        let scale_id = scale_id.to_string();
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_{}_{}", scale_id, note.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, scale, note)?,
+            manifest_closure: Box::new(move |m| {
+                let deps = match previous_note {
+                    None => vec![],
+                    Some(previous_note) => vec![Ustr::from(&format!(
+                        "{}::{}::{}",
+                        course_id,
+                        scale_id,
+                        previous_note.to_string()
+                    ))],
+                };
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Cross-String Sequences on the {} {} Scale",
+                        note.to_string(),
+                        scale.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} {} scale in diagonal patterns across two and three strings.",
+                        note.to_string(),
+                        scale.to_string()
+                    )))
+                    .dependencies(deps)
+                    .metadata(Some(BTreeMap::from([
+                        (MusicMetadata::Key.to_string(), vec![note.to_ascii_string()]),
+                        (
+                            MusicMetadata::ScaleType.to_string(),
+                            vec![scale.to_string().to_lowercase()],
+                        ),
+                    ])))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders =
+        generate_lesson_builders(ScaleType::Major, "major", note_aliases::no_alias)?;
+    lesson_builders.extend(generate_lesson_builders(
+        ScaleType::Minor,
+        "minor",
+        note_aliases::relative_minor_alias,
+    )?);
+
+    Ok(CourseBuilder {
+        directory_name: "cross_string_sequences".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Cross-String Sequences".to_string(),
+            dependencies: vec![*scale_sequences::COURSE_ID],
+            description: Some(
+                "Play the major and minor scales in all keys in diagonal patterns across strings."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["scales"])
+    .with_standard_instruction_asset(indoc! {"
+        Cross-string sequences play each note of a group in a different string, so the
+        pattern moves diagonally across the fretboard instead of along the strings.
+
+        Roll the fretting finger or use a different finger for each note so that the
+        notes don't ring together. Mute each string right after it's played.
+    "}))
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::dadgad::major_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_dadgad_major_scale")
+        .scale(GuitarScale::Trane(ScaleType::Major))
+        .tuning(tunings::DADGAD.to_vec())
+        .tuning_name("DADGAD")
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Folk])
+        .instructions(indoc! {"
+            DADGAD tuning lowers the sixth, second, and first strings by a whole step from
+            standard tuning. The open strings only contain the notes D, A, and G, so they can
+            be left ringing as drones under melodies in keys such as D or G, which is the
+            sound of much Celtic and acoustic music.
+
+            The fingerings of the major scale in standard tuning no longer work in the
+            strings that were retuned. Notes in those strings are found two frets higher than
+            before, and the scale is often played across fewer frets by relying on the open
+            strings.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fretboard::dadgad::major_scale::course_builder;
+
+    #[test]
+    fn lessons() -> anyhow::Result<()> {
+        let course_builder = course_builder()?;
+        // One lesson for each key in the circle of fifths, with one exercise for each string. The
+        // strings tuned to the same note still get their own exercise.
+        assert_eq!(course_builder.lesson_builders.len(), 15);
+        for lesson_builder in &course_builder.lesson_builders {
+            let directory_names = lesson_builder
+                .exercise_builders
+                .iter()
+                .map(|exercise_builder| exercise_builder.directory_name.as_str())
+                .collect::<Vec<&str>>();
+            assert_eq!(
+                directory_names,
+                vec![
+                    "D_6_string",
+                    "A_5_string",
+                    "D_4_string",
+                    "G_3_string",
+                    "A_2_string",
+                    "D_1_string"
+                ]
+            );
+        }
+        Ok(())
+    }
+}
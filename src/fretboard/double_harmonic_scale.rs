@@ -0,0 +1,35 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::double_harmonic");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_double_harmonic_scale")
+        .scale(GuitarScale::Custom(CustomScale::DoubleHarmonic))
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Metal])
+        .instructions(indoc! {"
+            The double harmonic scale, also called the Byzantine scale, is formed by the
+            degrees 1, ♭2, 3, 4, 5, ♭6, and 7. It's found in Greek and Turkish music, and
+            it's often used in metal. Its two augmented seconds, between the second and
+            third degrees and between the sixth and seventh degrees, give it its exotic
+            sound.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -1,20 +1,41 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use indoc::{formatdoc, indoc};
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+};
 use trane::{
     course_builder::{
-        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
-        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder, TraneMetadata,
+        music::circle_fifths::CircleFifthsCourse, AssetBuilder, CourseBuilder, ExerciseBuilder,
+        LessonBuilder,
     },
     data::{
         music::{notes::Note, scales::ScaleType},
-        BasicAsset, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
-        LessonManifestBuilder,
+        CourseManifest, ExerciseManifestBuilder, LessonManifestBuilder,
     },
 };
 use ustr::Ustr;
 
-use crate::AUTHORS;
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{
+        guitar_string::GuitarString,
+        lesson_factory::{
+            make_scale_lesson, make_single_string_scale_lesson, scale_exercise_manifest_template,
+            scale_lesson_id,
+        },
+        note_aliases::validate_note_alias,
+    },
+    instructions,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata, STRING_KEY},
+    music::{
+        augmented_seconds, note_to_fret, respell_enharmonic, semitones, semitones_up, GuitarScale,
+        NoteExt,
+    },
+    practice_log::add_practice_logs,
+    tunings,
+};
 
 /// A course to explore a given scale all over the fretboard.
 pub struct FretboardExplorationCourse {
@@ -27,75 +48,618 @@ pub struct FretboardExplorationCourse {
     /// The name of the directory under which the course will be stored.
     pub directory_name: String,
 
-    /// The scale type that this course is about.
-    pub scale: ScaleType,
+    /// The scale that this course is about.
+    pub scale: GuitarScale,
 
     /// An optinal function used to generate the name of the note for the lesson. Useful, for
-    /// example, to generate a course on the minor scale which follows the circle of fifths.
-    pub note_alias: Option<fn(Note) -> Result<Note>>,
+    /// example, to generate a course on the minor scale which follows the circle of fifths. Use
+    /// `compose_note_aliases` to apply more than one transformation.
+    pub note_alias: Option<Box<dyn Fn(Note) -> Result<Note>>>,
+
+    /// An optional vector of strings to represent the tuning of the guitar, from the lowest to the
+    /// highest string. If not provided, the standard tuning will be used. Strings tuned to the
+    /// same note are only explored once.
+    pub tuning: Option<Vec<GuitarString>>,
+
+    /// The name of the tuning, if it's not the standard tuning. It's added to the name of the
+    /// course to tell it apart from the course in standard tuning.
+    pub tuning_name: Option<String>,
+
+    /// An optional list of the indices of the strings in the tuning, starting at zero, which are
+    /// used to generate the exercises. If not provided, all the strings are used.
+    pub string_subset: Option<Vec<usize>>,
+
+    /// Whether to display the notes in the exercises using flats instead of sharps. Notes whose
+    /// spelling in the scale uses the other accidental are shown along with that spelling.
+    pub prefer_flats: bool,
+
+    /// An optional range of frets, given as the lowest and highest frets, to which the exploration
+    /// of the scale is restricted. If not provided, the first two octaves of each string are used.
+    pub fret_range: Option<(u8, u8)>,
+
+    /// Whether to add exercises to explore the scale across each pair of adjacent strings, in
+    /// addition to the exercises for each individual string.
+    pub include_adjacent_string_pairs: bool,
+
+    /// Whether to respell the keys of the lessons using their conventional spelling (e.g., the
+    /// lesson for C♭ becomes the lesson for B, and the lesson for C♯ becomes the lesson for D♭).
+    /// Lessons whose respelled key is the same as the key of another lesson are removed.
+    pub respell_enharmonics: bool,
+
+    /// Whether to list the open strings whose notes belong to the scale in the back of each card.
+    /// Useful for open tunings, in which the open strings can ring along with the scale.
+    pub describe_open_strings: bool,
+
+    /// An optional template for a practice log which is written along with the assets of each
+    /// lesson. The `{lesson_name}` and `{date}` placeholders are replaced by the name of the lesson
+    /// and the date in which the course was built.
+    pub practice_log_template: Option<String>,
+
+    /// The difficulty of the exercises in this course.
+    pub difficulty: Difficulty,
+
+    /// The genres to which the material of this course is relevant.
+    pub genres: Vec<Genre>,
+
+    /// An optional URL to a backing track to use with the exercises, which is linked from the back
+    /// of each card.
+    pub backing_track_url: Option<String>,
+
+    /// Optional instructions specific to this course, which are added after the instructions
+    /// common to all the scale courses.
+    pub instructions: Option<String>,
+
+    /// An optional function which generates additional lessons, such as lessons connecting this
+    /// course with another one. The lessons are added after the lessons for each key.
+    pub extra_lessons_generator: Option<Rc<dyn Fn() -> Result<Vec<LessonBuilder>>>>,
+
+    /// An optional function which returns the lessons in other courses on which the lesson for the
+    /// given note also depends, besides the lesson for the previous key.
+    pub lesson_dependencies: Option<Rc<dyn Fn(Note) -> Vec<Ustr>>>,
+
+    /// An optional list of the IDs of the courses recommended after this one, which is stored in
+    /// the metadata of the course.
+    pub recommended_next: Option<Vec<Ustr>>,
+
+    /// The template used to build the manifests of all the lessons in the course.
+    lesson_manifest_template: LessonManifestBuilder,
+
+    /// The template used to build the manifests of all the exercises in the course. The ID of the
+    /// lesson is set separately for each lesson.
+    exercise_manifest_template: ExerciseManifestBuilder,
+}
+
+/// The options which change the contents of the exercises in a scale course.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ExerciseOptions {
+    /// Whether to display the notes using flats instead of sharps.
+    pub prefer_flats: bool,
+
+    /// The range of frets to which the exploration of the scale is restricted, if any.
+    pub fret_range: Option<(u8, u8)>,
+
+    /// Whether to list the open strings whose notes belong to the scale.
+    pub describe_open_strings: bool,
+}
+
+/// Returns a note alias which applies the first alias and then the second one to the result.
+pub fn compose_note_aliases(
+    f: fn(Note) -> Result<Note>,
+    g: fn(Note) -> Result<Note>,
+) -> impl Fn(Note) -> Result<Note> {
+    move |note| g(f(note)?)
+}
 
-    /// An optional vector of notes to represent the tuning of the guitar. If not provided, the
-    /// standard tuning will be used.
-    pub tuning: Option<Vec<Note>>,
+/// A builder for `FretboardExplorationCourse`. The course ID and the directory name are required.
+/// The rest of the fields are optional and default to the major scale in standard tuning with no
+/// dependencies.
+#[derive(Default)]
+pub struct FretboardExplorationCourseBuilder {
+    course_id: Option<Ustr>,
+    dependencies: Vec<Ustr>,
+    directory_name: Option<String>,
+    scale: Option<GuitarScale>,
+    note_alias: Option<Box<dyn Fn(Note) -> Result<Note>>>,
+    tuning: Option<Vec<GuitarString>>,
+    tuning_name: Option<String>,
+    string_subset: Option<Vec<usize>>,
+    prefer_flats: bool,
+    fret_range: Option<(u8, u8)>,
+    include_adjacent_string_pairs: bool,
+    respell_enharmonics: bool,
+    describe_open_strings: bool,
+    practice_log_template: Option<String>,
+    difficulty: Option<Difficulty>,
+    genres: Vec<Genre>,
+    backing_track_url: Option<String>,
+    instructions: Option<String>,
+    extra_lessons_generator: Option<Rc<dyn Fn() -> Result<Vec<LessonBuilder>>>>,
+    lesson_dependencies: Option<Rc<dyn Fn(Note) -> Vec<Ustr>>>,
+    recommended_next: Option<Vec<Ustr>>,
+}
+
+impl FretboardExplorationCourseBuilder {
+    /// Sets the ID of the course.
+    pub fn course_id(mut self, course_id: Ustr) -> Self {
+        self.course_id = Some(course_id);
+        self
+    }
+
+    /// Sets the dependencies of the course.
+    pub fn dependencies(mut self, dependencies: Vec<Ustr>) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Sets the name of the directory under which the course will be stored.
+    pub fn directory_name(mut self, directory_name: &str) -> Self {
+        self.directory_name = Some(directory_name.to_string());
+        self
+    }
+
+    /// Sets the scale that the course is about.
+    pub fn scale(mut self, scale: GuitarScale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Sets the function used to generate the name of the note for each lesson.
+    pub fn note_alias(mut self, note_alias: impl Fn(Note) -> Result<Note> + 'static) -> Self {
+        self.note_alias = Some(Box::new(note_alias));
+        self
+    }
+
+    /// Sets the tuning of the guitar.
+    pub fn tuning(mut self, tuning: Vec<GuitarString>) -> Self {
+        self.tuning = Some(tuning);
+        self
+    }
+
+    /// Sets the name of the tuning of the guitar.
+    pub fn tuning_name(mut self, tuning_name: &str) -> Self {
+        self.tuning_name = Some(tuning_name.to_string());
+        self
+    }
+
+    /// Sets the indices of the strings in the tuning which are used to generate the exercises.
+    #[allow(dead_code)]
+    pub fn string_subset(mut self, string_subset: Vec<usize>) -> Self {
+        self.string_subset = Some(string_subset);
+        self
+    }
+
+    /// Sets whether to display the notes using flats instead of sharps.
+    #[allow(dead_code)]
+    pub fn prefer_flats(mut self, prefer_flats: bool) -> Self {
+        self.prefer_flats = prefer_flats;
+        self
+    }
+
+    /// Sets the range of frets to which the exploration of the scale is restricted.
+    #[allow(dead_code)]
+    pub fn fret_range(mut self, lowest_fret: u8, highest_fret: u8) -> Self {
+        self.fret_range = Some((lowest_fret, highest_fret));
+        self
+    }
+
+    /// Sets whether to add exercises for each pair of adjacent strings.
+    #[allow(dead_code)]
+    pub fn include_adjacent_string_pairs(mut self, include_adjacent_string_pairs: bool) -> Self {
+        self.include_adjacent_string_pairs = include_adjacent_string_pairs;
+        self
+    }
+
+    /// Sets whether to respell the keys of the lessons using their conventional spelling.
+    #[allow(dead_code)]
+    pub fn respell_enharmonics(mut self, respell_enharmonics: bool) -> Self {
+        self.respell_enharmonics = respell_enharmonics;
+        self
+    }
+
+    /// Sets whether to list the open strings whose notes belong to the scale.
+    pub fn describe_open_strings(mut self, describe_open_strings: bool) -> Self {
+        self.describe_open_strings = describe_open_strings;
+        self
+    }
+
+    /// Sets the template of the practice log written along with each lesson.
+    #[allow(dead_code)]
+    pub fn practice_log_template(mut self, practice_log_template: &str) -> Self {
+        self.practice_log_template = Some(practice_log_template.to_string());
+        self
+    }
+
+    /// Sets the difficulty of the exercises.
+    pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Sets the genres to which the material of the course is relevant.
+    pub fn genres(mut self, genres: Vec<Genre>) -> Self {
+        self.genres = genres;
+        self
+    }
+
+    /// Sets the URL of the backing track to use with the exercises.
+    #[allow(dead_code)]
+    pub fn backing_track_url(mut self, backing_track_url: &str) -> Self {
+        self.backing_track_url = Some(backing_track_url.to_string());
+        self
+    }
+
+    /// Sets the instructions specific to this course.
+    pub fn instructions(mut self, instructions: &str) -> Self {
+        self.instructions = Some(instructions.to_string());
+        self
+    }
+
+    /// Sets the function which generates the additional lessons of the course.
+    pub fn extra_lessons_generator(
+        mut self,
+        extra_lessons_generator: impl Fn() -> Result<Vec<LessonBuilder>> + 'static,
+    ) -> Self {
+        self.extra_lessons_generator = Some(Rc::new(extra_lessons_generator));
+        self
+    }
+
+    /// Sets the function which returns the additional dependencies of the lesson for each note.
+    pub fn lesson_dependencies(
+        mut self,
+        lesson_dependencies: impl Fn(Note) -> Vec<Ustr> + 'static,
+    ) -> Self {
+        self.lesson_dependencies = Some(Rc::new(lesson_dependencies));
+        self
+    }
+
+    /// Sets the IDs of the courses recommended after this one.
+    pub fn recommended_next(mut self, recommended_next: Vec<Ustr>) -> Self {
+        self.recommended_next = Some(recommended_next);
+        self
+    }
+
+    /// Builds the course, returning an error if any of the required fields is missing.
+    pub fn build(self) -> Result<FretboardExplorationCourse> {
+        let course_id = self
+            .course_id
+            .ok_or_else(|| anyhow!("the course ID of the scale course is required"))?;
+        if let Some((lowest_fret, highest_fret)) = self.fret_range {
+            if lowest_fret > highest_fret {
+                return Err(anyhow!(
+                    "invalid fret range {}-{} for course {}",
+                    lowest_fret,
+                    highest_fret,
+                    course_id
+                ));
+            }
+        }
+        Ok(FretboardExplorationCourse {
+            course_id,
+            dependencies: self.dependencies,
+            directory_name: self
+                .directory_name
+                .ok_or_else(|| anyhow!("the directory name of the scale course is required"))?,
+            scale: self.scale.unwrap_or(GuitarScale::Trane(ScaleType::Major)),
+            note_alias: self.note_alias,
+            tuning: self.tuning,
+            tuning_name: self.tuning_name,
+            string_subset: self.string_subset,
+            prefer_flats: self.prefer_flats,
+            fret_range: self.fret_range,
+            include_adjacent_string_pairs: self.include_adjacent_string_pairs,
+            respell_enharmonics: self.respell_enharmonics,
+            describe_open_strings: self.describe_open_strings,
+            practice_log_template: self.practice_log_template,
+            difficulty: self.difficulty.unwrap_or(Difficulty::Beginner),
+            genres: self.genres,
+            backing_track_url: self.backing_track_url,
+            instructions: self.instructions,
+            extra_lessons_generator: self.extra_lessons_generator,
+            lesson_dependencies: self.lesson_dependencies,
+            recommended_next: self.recommended_next,
+            lesson_manifest_template: LessonManifestBuilder::default()
+                .course_id(course_id)
+                .clone(),
+            exercise_manifest_template: scale_exercise_manifest_template(course_id),
+        })
+    }
 }
 
 impl FretboardExplorationCourse {
-    /// Returns the standard tuning. The E string is only returned once since there's no point in
-    /// repeating it.
-    fn standard_tuning() -> Vec<Note> {
-        vec![Note::E, Note::A, Note::D, Note::G, Note::B]
+    /// Returns a builder to create a new course.
+    pub fn builder() -> FretboardExplorationCourseBuilder {
+        FretboardExplorationCourseBuilder::default()
+    }
+
+    /// Returns the template used to build the manifests of the lessons in the course.
+    pub fn lesson_manifest_template(&self) -> &LessonManifestBuilder {
+        &self.lesson_manifest_template
+    }
+
+    /// Returns the template used to build the manifests of the exercises in the course. The lesson
+    /// ID must be set before building an exercise manifest from it.
+    pub fn exercise_manifest_template(&self) -> &ExerciseManifestBuilder {
+        &self.exercise_manifest_template
+    }
+
+    /// Returns the strings of the guitar in standard tuning, from the lowest to the highest.
+    pub(crate) fn standard_tuning() -> Vec<GuitarString> {
+        tunings::STANDARD.to_vec()
     }
 
-    /// Generates the exercise builders for the lesson with the given scale and note.
-    fn generate_exercise_builders(
+    /// Returns the strings used to generate the exercises.
+    fn selected_strings(&self) -> Result<Vec<GuitarString>> {
+        let strings = self.tuning.as_deref().unwrap_or(&tunings::STANDARD);
+        match &self.string_subset {
+            None => Ok(strings.to_vec()),
+            Some(string_subset) => string_subset
+                .iter()
+                .map(|index| {
+                    strings.get(*index).copied().ok_or_else(|| {
+                        anyhow!(
+                            "string index {} is out of bounds for the tuning of course {}",
+                            index,
+                            self.course_id
+                        )
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the name of the note in the preferred accidental style. If the note is spelled
+    /// differently in the scale, that spelling is also included.
+    fn note_name(note: Note, prefer_flats: bool) -> String {
+        let preferred = note.to_string_with_accidental(prefer_flats);
+        if preferred == note.to_string() {
+            preferred
+        } else {
+            format!("{} ({})", preferred, note.to_string())
+        }
+    }
+
+    /// Returns a sentence describing the augmented seconds in the scale, which give scales like the
+    /// double harmonic scale their distinctive sound. Returns an empty string if there are none.
+    fn augmented_seconds_answer(scale_notes: &[Note], prefer_flats: bool) -> String {
+        let pairs = augmented_seconds(scale_notes)
+            .iter()
+            .map(|(lower, upper)| {
+                format!(
+                    "{} and {}",
+                    Self::note_name(*lower, prefer_flats),
+                    Self::note_name(*upper, prefer_flats)
+                )
+            })
+            .collect::<Vec<String>>();
+        if pairs.is_empty() {
+            return String::new();
+        }
+        format!(
+            " The augmented seconds between {} give the scale its distinctive sound.",
+            pairs.join(" and between ")
+        )
+    }
+
+    /// Returns a sentence listing the notes of the scale which belong to the chord over which the
+    /// scale is usually played. Returns an empty string if the scale has no such chord.
+    fn underlying_chord_answer(
+        scale: GuitarScale,
+        note: Note,
+        scale_notes: &[Note],
+        prefer_flats: bool,
+    ) -> String {
+        match scale.underlying_chord() {
+            None => String::new(),
+            Some((suffix, intervals)) => format!(
+                " The notes shared with the {}{} chord are: {}.",
+                note.to_string(),
+                suffix,
+                scale_notes
+                    .iter()
+                    .filter(|n| intervals.contains(&semitones_up(note, **n)))
+                    .map(|n| Self::note_name(*n, prefer_flats))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    /// Returns a sentence marking the notes which give the scale its character. Returns an empty
+    /// string if the scale has no such notes.
+    fn characteristic_notes_answer(
+        scale: GuitarScale,
+        note: Note,
+        scale_notes: &[Note],
+        prefer_flats: bool,
+    ) -> String {
+        let descriptions = scale
+            .characteristic_notes()
+            .iter()
+            .filter_map(|(interval, description)| {
+                scale_notes
+                    .iter()
+                    .find(|n| semitones_up(note, **n) == *interval)
+                    .map(|n| format!("{} is {}", Self::note_name(*n, prefer_flats), description))
+            })
+            .collect::<Vec<String>>();
+        match descriptions.split_last() {
+            None => String::new(),
+            Some((last, [])) => format!(" {}.", last),
+            Some((last, rest)) => format!(" {}, and {}.", rest.join(", "), last),
+        }
+    }
+
+    /// Returns a paragraph listing the open strings whose notes belong to the scale. The strings are
+    /// named by their number, since several strings can be tuned to the same note.
+    fn open_strings_answer(strings: &[GuitarString], scale_notes: &[Note]) -> String {
+        let open_strings = strings
+            .iter()
+            .filter(|guitar_string| {
+                scale_notes
+                    .iter()
+                    .any(|n| semitones(*n) == semitones(guitar_string.open_note))
+            })
+            .map(|guitar_string| {
+                format!(
+                    "{} ({})",
+                    guitar_string.string_number,
+                    guitar_string.open_note.to_string()
+                )
+            })
+            .collect::<Vec<String>>();
+        match open_strings.as_slice() {
+            [] => "\nNone of the open strings are part of the scale.\n".to_string(),
+            [open_string] => format!(
+                "\nThe open string {} is already part of the scale.\n",
+                open_string
+            ),
+            [first, second] => format!(
+                "\nThe open strings {} and {} are already part of the scale.\n",
+                first, second
+            ),
+            [rest @ .., last] => format!(
+                "\nThe open strings {}, and {} are already part of the scale.\n",
+                rest.join(", "),
+                last
+            ),
+        }
+    }
+
+    /// Returns the frets at which the note is found in the given string. If there's no fret range,
+    /// the lowest two octaves of the string are used.
+    fn note_frets(guitar_string: Note, note: Note, fret_range: Option<(u8, u8)>) -> Vec<u8> {
+        match fret_range {
+            None => note_to_fret(guitar_string, note),
+            Some((lowest_fret, highest_fret)) => (lowest_fret..=highest_fret)
+                .filter(|fret| fret % 12 == semitones_up(guitar_string, note))
+                .collect(),
+        }
+    }
+
+    /// Returns a markdown table with the frets at which each note of the scale is found in the
+    /// given string.
+    fn fret_table(
+        guitar_string: Note,
+        scale_notes: &[Note],
+        prefer_flats: bool,
+        fret_range: Option<(u8, u8)>,
+    ) -> String {
+        let rows = scale_notes
+            .iter()
+            .map(|n| {
+                let frets = Self::note_frets(guitar_string, *n, fret_range)
+                    .iter()
+                    .map(|fret| fret.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                // Narrow fret ranges might not contain every note of the scale.
+                let frets = if frets.is_empty() {
+                    "-".to_string()
+                } else {
+                    frets
+                };
+                format!("| {} | {} |", Self::note_name(*n, prefer_flats), frets)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        formatdoc! {"
+            | Note | Frets |
+            |------|-------|
+            {}
+        ", rows}
+    }
+
+    /// Generates the exercise builders for the lesson with the given scale and note. There's one
+    /// exercise for each of the given strings.
+    pub(crate) fn generate_exercise_builders(
         course_id: Ustr,
-        scale: ScaleType,
+        scale: GuitarScale,
         note: Note,
-        tuning: Option<Vec<Note>>,
+        strings: &[GuitarString],
+        options: ExerciseOptions,
+        exercise_metadata: &GuitarExerciseMetadata,
     ) -> Result<Vec<ExerciseBuilder>> {
-        let scale_notes = scale.notes(note)?.notes;
+        let ExerciseOptions {
+            prefer_flats,
+            fret_range,
+            describe_open_strings,
+        } = options;
+        let scale_notes = scale.notes(note)?;
         let scale_answer = scale_notes
             .iter()
-            .map(|n| n.to_string())
+            .map(|n| Self::note_name(*n, prefer_flats))
             .collect::<Vec<String>>()
             .join(", ");
-        let mut builders = vec![];
-        let tuning = match tuning {
-            None => Self::standard_tuning(),
-            Some(tuning) => tuning,
+        let scale_remarks = format!(
+            "{}{}{}",
+            Self::augmented_seconds_answer(&scale_notes, prefer_flats),
+            Self::characteristic_notes_answer(scale, note, &scale_notes, prefer_flats),
+            Self::underlying_chord_answer(scale, note, &scale_notes, prefer_flats)
+        );
+        let open_strings_answer = if describe_open_strings {
+            Self::open_strings_answer(strings, &scale_notes)
+        } else {
+            String::new()
         };
-
-        for guitar_string in tuning {
+        let (range_instructions, range_answer) = match fret_range {
+            None => (String::new(), String::new()),
+            Some((lowest_fret, highest_fret)) => (
+                format!(
+                    "\nOnly use the frets between fret {} and fret {}.\n",
+                    lowest_fret, highest_fret
+                ),
+                format!(", between fret {} and fret {}", lowest_fret, highest_fret),
+            ),
+        };
+        let mut builders = vec![];
+        for guitar_string in strings.iter().copied() {
+            let open_note = guitar_string.open_note;
+            let string_number = guitar_string.string_number;
             builders.push(ExerciseBuilder {
-                directory_name: format!("{}_string", guitar_string.to_string()),
+                directory_name: format!("{}_{}_string", open_note.to_ascii_string(), string_number),
                 asset_builders: vec![
                     AssetBuilder {
                         file_name: "front.md".to_string(),
                         contents: formatdoc! {"
-                           Explore the {} {} scale in the {} string. 
-                        ", note.to_string(), scale.to_string(), guitar_string.to_string()},
+                           Explore the {} {} scale in string {} ({}). 
+                           {}", note.to_string(), scale, string_number, guitar_string, range_instructions},
                     },
                     AssetBuilder {
                         file_name: "back.md".to_string(),
-                        contents: formatdoc! {"
-                            The notes of the {} {} scale are: {}.
-                        ", note.to_string(), scale.to_string(), scale_answer},
+                        contents: exercise_metadata.with_backing_track(formatdoc! {"
+                            The notes of the {} {} scale are: {}.{}
+
+                            They are found in the following frets of string {} ({}){}:
+
+                            {}{}
+                        ",
+                        note.to_string(),
+                        scale,
+                        scale_answer,
+                        scale_remarks,
+                        string_number,
+                        guitar_string,
+                        range_answer,
+                        Self::fret_table(open_note, &scale_notes, prefer_flats, fret_range),
+                        open_strings_answer}),
                     },
                 ],
                 manifest_closure: Box::new(move |m| {
                     #[allow(clippy::redundant_clone)]
                     m.clone()
                         .id(format!(
-                            "{}::{}::{}_string",
+                            "{}::{}::{}_{}_string",
                             course_id,
                             note.to_string(),
-                            guitar_string.to_string(),
+                            open_note.to_string(),
+                            string_number,
                         ))
                         .name(format!(
-                            "Explore the {} {} scale in the {} string",
+                            "Explore the {} {} scale in string {} ({})",
                             note.to_string(),
-                            scale.to_string(),
-                            guitar_string.to_string()
+                            scale,
+                            string_number,
+                            guitar_string
                         ))
                         .clone()
                 }),
@@ -105,124 +669,514 @@ impl FretboardExplorationCourse {
         Ok(builders)
     }
 
+    /// Generates the exercise builders to explore the scale across each pair of adjacent strings
+    /// with the given scale and note.
+    fn generate_string_pair_exercise_builders(
+        course_id: Ustr,
+        scale: GuitarScale,
+        note: Note,
+        strings: &[GuitarString],
+        options: ExerciseOptions,
+        exercise_metadata: &GuitarExerciseMetadata,
+    ) -> Result<Vec<ExerciseBuilder>> {
+        let ExerciseOptions {
+            prefer_flats,
+            fret_range,
+            ..
+        } = options;
+        let scale_notes = scale.notes(note)?;
+        let scale_answer = scale_notes
+            .iter()
+            .map(|n| Self::note_name(*n, prefer_flats))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let scale_remarks = format!(
+            "{}{}{}",
+            Self::augmented_seconds_answer(&scale_notes, prefer_flats),
+            Self::characteristic_notes_answer(scale, note, &scale_notes, prefer_flats),
+            Self::underlying_chord_answer(scale, note, &scale_notes, prefer_flats)
+        );
+        Ok(strings
+            .windows(2)
+            .filter(|pair| pair[0].string_number == pair[1].string_number + 1)
+            .map(|pair| {
+                let (low_number, low_string) = (pair[0].string_number, pair[0].open_note);
+                let (high_number, high_string) = (pair[1].string_number, pair[1].open_note);
+                let pair_id = format!(
+                    "{}_{}_and_{}_{}_strings",
+                    low_string.to_ascii_string(),
+                    low_number,
+                    high_string.to_ascii_string(),
+                    high_number
+                );
+                let exercise_id = format!("{}::{}::{}", course_id, note.to_string(), pair_id);
+
+                ExerciseBuilder {
+                    directory_name: pair_id,
+                    asset_builders: vec![
+                        AssetBuilder {
+                            file_name: "front.md".to_string(),
+                            contents: formatdoc! {"
+                                Explore the {} {} scale across the {} (string {}) and {} (string {})
+                                strings.
+                            ",
+                            note.to_string(),
+                            scale,
+                            low_string.to_string(),
+                            low_number,
+                            high_string.to_string(),
+                            high_number},
+                        },
+                        AssetBuilder {
+                            file_name: "back.md".to_string(),
+                            contents: exercise_metadata.with_backing_track(formatdoc! {"
+                                The notes of the {} {} scale are: {}.{}
+
+                                They are found in the following frets of the {} string:
+
+                                {}
+                                They are found in the following frets of the {} string:
+
+                                {}
+                                Move between both strings without breaking the flow of the
+                                scale. Cross to the other string when the next note is closer
+                                to the hand there.
+                            ",
+                            note.to_string(),
+                            scale,
+                            scale_answer,
+                            scale_remarks,
+                            low_string.to_string(),
+                            Self::fret_table(low_string, &scale_notes, prefer_flats, fret_range),
+                            high_string.to_string(),
+                            Self::fret_table(high_string, &scale_notes, prefer_flats, fret_range)}),
+                        },
+                    ],
+                    manifest_closure: Box::new(move |m| {
+                        #[allow(clippy::redundant_clone)]
+                        m.clone()
+                            .id(exercise_id.clone())
+                            .name(format!(
+                                "Explore the {} {} scale in the {} and {} strings",
+                                note.to_string(),
+                                scale,
+                                low_string.to_string(),
+                                high_string.to_string()
+                            ))
+                            .clone()
+                    }),
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the contents of the instructions for the course.
+    fn instructions_contents(&self) -> String {
+        let general_instructions = format!(
+            "{}\n{}",
+            indoc! {"
+                Inspired by an exercise from the book *The Advancing guitarist*.
+
+                Explore the scale in each individual string without jumping across
+                multiple strings. Explore different fingerings, techniques, dynamics,
+                etc.
+            "},
+            instructions::backing_track_suggestion()
+        );
+        match &self.instructions {
+            None => general_instructions,
+            Some(instructions) => format!("{}\n{}", general_instructions, instructions),
+        }
+    }
+
     /// Generates the course builder for the scale course.
     pub fn course_builder(&self) -> Result<CourseBuilder> {
         let course_id = self.course_id;
         let scale = self.scale;
-        let tuning = self.tuning.to_owned();
+        let strings = self.selected_strings()?;
+        let mut string_notes: Vec<String> = vec![];
+        for guitar_string in &strings {
+            let string_note = guitar_string.open_note.to_ascii_string();
+            if !string_notes.contains(&string_note) {
+                string_notes.push(string_note);
+            }
+        }
+        let options = ExerciseOptions {
+            prefer_flats: self.prefer_flats,
+            fret_range: self.fret_range,
+            describe_open_strings: self.describe_open_strings,
+        };
+        let include_adjacent_string_pairs = self.include_adjacent_string_pairs;
+        let lesson_dependencies = self.lesson_dependencies.clone();
+        let exercise_manifest_template = self.exercise_manifest_template().clone();
+        if let Some(note_alias) = &self.note_alias {
+            validate_note_alias(note_alias.as_ref())?;
+        }
+
+        // The note alias cannot be moved into the lesson generator, so the alias of every key is
+        // computed in advance. `CircleFifthsCourse` only accepts function pointers, so the aliases
+        // are applied by the generator instead.
+        let aliases = Note::all_keys(true)
+            .into_iter()
+            .map(|key| {
+                let alias = match &self.note_alias {
+                    None => key,
+                    Some(note_alias) => note_alias(key)?,
+                };
+                if self.respell_enharmonics {
+                    Ok((key, respell_enharmonic(alias)))
+                } else {
+                    Ok((key, alias))
+                }
+            })
+            .collect::<Result<Vec<(Note, Note)>>>()?;
+        let alias = move |key: Note| {
+            aliases
+                .iter()
+                .find(|(original, _)| *original == key)
+                .map_or(key, |(_, alias)| *alias)
+        };
+        let exercise_metadata = GuitarExerciseMetadata {
+            recommended_bpm: None,
+            difficulty: Some(self.difficulty),
+            backing_track_url: self.backing_track_url.clone(),
+        };
 
         let course_generator = CircleFifthsCourse {
             directory_name: self.directory_name.clone(),
             course_manifest: CourseManifest {
                 id: self.course_id,
-                name: format!("Explore the {} Scale in the fretboard", scale.to_string()),
+                name: match &self.tuning_name {
+                    None => format!("Explore the {} Scale in the fretboard", scale),
+                    Some(tuning_name) => format!(
+                        "Explore the {} Scale in the fretboard ({} Tuning)",
+                        scale, tuning_name
+                    ),
+                },
                 dependencies: self.dependencies.clone(),
                 description: Some(format!(
                     "Explore the {} scale in all strings in the fretboard for all keys.",
-                    scale.to_string()
+                    scale
                 )),
                 authors: Some(vec![AUTHORS.to_string()]),
-                metadata: Some(BTreeMap::from([
-                    (TraneMetadata::Skill.to_string(), vec!["music".to_string()]),
-                    (
-                        MusicMetadata::Instrument.to_string(),
-                        vec!["guitar".to_string()],
-                    ),
-                    (
-                        MusicMetadata::MusicalSkill.to_string(),
-                        vec!["fretboard".to_string()],
-                    ),
-                    (
-                        MusicMetadata::MusicalConcept.to_string(),
-                        vec!["scales".to_string()],
-                    ),
-                    (
-                        MusicMetadata::ScaleType.to_string(),
-                        vec![scale.to_string().to_lowercase()],
-                    ),
-                ])),
+                metadata: None,
                 course_material: None,
-                course_instructions: Some(BasicAsset::MarkdownAsset {
-                    path: "course_instructions.md".to_string(),
-                }),
+                course_instructions: None,
                 generator_config: None,
             },
-            course_asset_builders: vec![AssetBuilder {
-                file_name: "course_instructions.md".to_string(),
-                contents: indoc! {"
-                        Inspired by an exercise from the book *The Advancing guitarist*.
-
-                        Explore the scale in each individual string without jumping across
-                        multiple strings. Explore different fingerings, techniques, dynamics,
-                        etc.
-
-                        You can use a vamp or backing track, although they are not provided
-                        here.
-                    "}
-                .to_string(),
-            }],
-            note_alias: self.note_alias,
-            lesson_manifest_template: LessonManifestBuilder::default()
-                .course_id(self.course_id)
-                .clone(),
+            course_asset_builders: vec![],
+            note_alias: None,
+            lesson_manifest_template: self.lesson_manifest_template().clone(),
             lesson_builder_generator: Box::new(move |note, previous_note| {
-                let lesson_id = format!("{}::{}", course_id, note.to_string());
-
-                Ok(LessonBuilder {
-                    directory_name: format!("lesson_{}", note.to_ascii_string()),
-                    exercise_manifest_template: ExerciseManifestBuilder::default()
-                        .course_id(course_id)
-                        .lesson_id(lesson_id)
-                        .exercise_type(ExerciseType::Procedural)
-                        .exercise_asset(ExerciseAsset::FlashcardAsset {
-                            front_path: "front.md".to_string(),
-                            back_path: Some("back.md".to_string()),
-                        })
-                        .clone(),
-                    asset_builders: vec![],
-                    exercise_builders: Self::generate_exercise_builders(
+                let note = alias(note);
+                let previous_note = previous_note.map(&alias);
+                let exercise_metadata = exercise_metadata.clone();
+                let mut dependencies = previous_note
+                    .map(|previous_note| scale_lesson_id(course_id, previous_note))
+                    .into_iter()
+                    .collect::<Vec<Ustr>>();
+                if let Some(lesson_dependencies) = &lesson_dependencies {
+                    dependencies.extend(lesson_dependencies(note));
+                }
+                if let [guitar_string] = strings.as_slice() {
+                    return make_single_string_scale_lesson(
+                        course_id,
+                        note,
+                        scale,
+                        *guitar_string,
+                        options,
+                        &exercise_metadata,
+                        dependencies,
+                    );
+                }
+
+                let string_notes = string_notes.clone();
+                let mut exercise_builders = Self::generate_exercise_builders(
+                    course_id,
+                    scale,
+                    note,
+                    &strings,
+                    options,
+                    &exercise_metadata,
+                )?;
+                if include_adjacent_string_pairs {
+                    exercise_builders.extend(Self::generate_string_pair_exercise_builders(
                         course_id,
                         scale,
                         note,
-                        tuning.clone(),
-                    )?,
-                    manifest_closure: Box::new(move |m| {
-                        let deps = match previous_note {
-                            None => vec![],
-                            Some(previous_note) => {
-                                let dep_id = Ustr::from(&format!(
-                                    "{}::{}",
-                                    course_id,
-                                    previous_note.to_string()
-                                ));
-                                vec![dep_id]
-                            }
-                        };
+                        &strings,
+                        options,
+                        &exercise_metadata,
+                    )?);
+                }
 
-                        #[allow(clippy::redundant_clone)]
-                        m.clone()
-                            .id(format!("{}::{}", course_id, note.to_string()))
-                            .name(format!(
-                                "Explore the {} {} Scale in the fretboard",
-                                note.to_string(),
-                                scale.to_string(),
-                            ))
-                            .description(Some(format!(
-                                "Explore the notes of the {} {} scale in the fretboard.",
-                                note.to_string(),
-                                scale.to_string(),
-                            )))
-                            .dependencies(deps)
-                            .metadata(Some(BTreeMap::from([(
-                                MusicMetadata::Key.to_string(),
-                                vec![note.to_ascii_string()],
-                            )])))
-                            .clone()
-                    }),
-                })
+                let mut metadata = BTreeMap::from([(STRING_KEY.to_string(), string_notes)]);
+                exercise_metadata.add_to(&mut metadata);
+                Ok(make_scale_lesson(
+                    course_id,
+                    note,
+                    scale,
+                    &exercise_manifest_template,
+                    exercise_builders,
+                    dependencies,
+                    metadata,
+                ))
+            }),
+            extra_lessons_generator: self.extra_lessons_generator.clone().map(|generator| {
+                Box::new(move || generator()) as Box<dyn Fn() -> Result<Vec<LessonBuilder>>>
             }),
-            extra_lessons_generator: None,
         };
-        course_generator.generate_course_builder()
+        let mut course_builder = course_generator
+            .generate_course_builder()?
+            .with_instrument_metadata()
+            .with_fretboard_skill_metadata()
+            .with_concept_metadata(&["scales"])
+            .with_scale_metadata(scale)
+            .with_genre_metadata(&self.genres)
+            .with_recommended_next_metadata(self.recommended_next.as_deref().unwrap_or_default())
+            .with_standard_instruction_asset(&self.instructions_contents());
+
+        // Respelling the keys can map two keys of the circle to the same key (e.g., C♭ and B), so
+        // only the first lesson for each key is kept.
+        if self.respell_enharmonics {
+            let mut directory_names = BTreeSet::new();
+            course_builder.lesson_builders.retain(|lesson_builder| {
+                directory_names.insert(lesson_builder.directory_name.clone())
+            });
+        }
+        if let Some(template) = &self.practice_log_template {
+            add_practice_logs(&mut course_builder, template)?;
+        }
+        Ok(course_builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::{notes::Note, scales::ScaleType};
+    use ustr::Ustr;
+
+    use crate::{
+        fretboard::{
+            fretboard_exploration::{compose_note_aliases, FretboardExplorationCourse},
+            guitar_string::GuitarString,
+        },
+        metadata::Difficulty,
+        music::{GuitarScale, Mode},
+        practice_log::PRACTICE_LOG_FILE,
+        tunings,
+        validation::validate_course_builder,
+    };
+
+    #[test]
+    fn composed_note_aliases() -> anyhow::Result<()> {
+        // The relative minor of the Dorian mode of C, which is B.
+        let alias =
+            compose_note_aliases(|key| Mode::Dorian.tonic(key), |note| note.relative_minor());
+        assert_eq!(alias(Note::C)?, Note::B);
+        assert!(alias(Note::C_SHARP).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn builder() -> anyhow::Result<()> {
+        assert!(FretboardExplorationCourse::builder()
+            .directory_name("course")
+            .build()
+            .is_err());
+        assert!(FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .build()
+            .is_err());
+
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .tuning(vec![
+                GuitarString::new(Note::D, 2, 5),
+                GuitarString::new(Note::A, 2, 4),
+                GuitarString::new(Note::D, 3, 3),
+                GuitarString::new(Note::G, 3, 2),
+                GuitarString::new(Note::B, 3, 1),
+            ])
+            .prefer_flats(true)
+            .backing_track_url("https://example.com/track")
+            .build()?;
+        assert!(matches!(course.scale, GuitarScale::Trane(ScaleType::Major)));
+        assert_eq!(course.difficulty, Difficulty::Beginner);
+        assert!(course.prefer_flats);
+        assert_eq!(course.tuning.map(|tuning| tuning.len()), Some(5));
+        assert!(course.dependencies.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn manifest_templates() -> anyhow::Result<()> {
+        let course_id = Ustr::from("course");
+        let course = FretboardExplorationCourse::builder()
+            .course_id(course_id)
+            .directory_name("course")
+            .build()?;
+
+        let lesson_manifest = course
+            .lesson_manifest_template()
+            .clone()
+            .id(Ustr::from("course::lesson"))
+            .name("Lesson".to_string())
+            .dependencies(vec![])
+            .build()?;
+        assert_eq!(lesson_manifest.course_id, course_id);
+
+        let exercise_manifest = course
+            .exercise_manifest_template()
+            .clone()
+            .id(Ustr::from("course::lesson::exercise"))
+            .lesson_id(Ustr::from("course::lesson"))
+            .name("Exercise".to_string())
+            .build()?;
+        assert_eq!(exercise_manifest.course_id, course_id);
+        Ok(())
+    }
+
+    #[test]
+    fn fret_range() -> anyhow::Result<()> {
+        assert!(FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .fret_range(5, 0)
+            .build()
+            .is_err());
+
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .fret_range(12, 19)
+            .build()?;
+        let course_builder = course.course_builder()?;
+        let exercise_builder = &course_builder.lesson_builders[0].exercise_builders[0];
+        assert!(exercise_builder.asset_builders[0]
+            .contents
+            .contains("between fret 12 and fret 19"));
+        // The C in the low E string is found in frets 8 and 20, which are outside the range.
+        assert!(exercise_builder.asset_builders[1]
+            .contents
+            .contains("| C | - |"));
+        assert!(exercise_builder.asset_builders[1]
+            .contents
+            .contains("| E | 12 |"));
+        Ok(())
+    }
+
+    /// Verifies that the strings tuned to the same note in different octaves get different cards.
+    #[test]
+    fn strings_with_same_open_note() -> anyhow::Result<()> {
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .tuning(tunings::OPEN_G.to_vec())
+            .build()?;
+        let course_builder = course.course_builder()?;
+        let exercise_builders = &course_builder.lesson_builders[0].exercise_builders;
+        assert_eq!(exercise_builders[0].directory_name, "D_6_string");
+        assert_eq!(exercise_builders[2].directory_name, "D_4_string");
+        for (low_asset, high_asset) in exercise_builders[0]
+            .asset_builders
+            .iter()
+            .zip(exercise_builders[2].asset_builders.iter())
+        {
+            assert_ne!(low_asset.contents, high_asset.contents);
+        }
+        assert!(exercise_builders[0].asset_builders[0]
+            .contents
+            .contains("string 6 (D2)"));
+        assert!(exercise_builders[2].asset_builders[0]
+            .contents
+            .contains("string 4 (D3)"));
+        Ok(())
+    }
+
+    #[test]
+    fn adjacent_string_pairs() -> anyhow::Result<()> {
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .include_adjacent_string_pairs(true)
+            .build()?;
+        let course_builder = course.course_builder()?;
+        let exercise_builders = &course_builder.lesson_builders[0].exercise_builders;
+        // Six exercises for the individual strings and five for the pairs of strings.
+        assert_eq!(exercise_builders.len(), 11);
+        assert_eq!(exercise_builders[6].directory_name, "E_6_and_A_5_strings");
+        assert_eq!(exercise_builders[10].directory_name, "B_2_and_E_1_strings");
+        Ok(())
+    }
+
+    #[test]
+    fn practice_log_template() -> anyhow::Result<()> {
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .practice_log_template("# {lesson_name}")
+            .build()?;
+        let course_builder = course.course_builder()?;
+        for lesson_builder in &course_builder.lesson_builders {
+            assert!(lesson_builder.asset_builders.iter().any(|asset_builder| {
+                asset_builder.file_name == PRACTICE_LOG_FILE
+                    && asset_builder.contents.starts_with("# Explore the")
+            }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn respell_enharmonics() -> anyhow::Result<()> {
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .respell_enharmonics(true)
+            .build()?;
+        let course_builder = course.course_builder()?;
+        let directory_names = course_builder
+            .lesson_builders
+            .iter()
+            .map(|lesson_builder| lesson_builder.directory_name.as_str())
+            .collect::<Vec<&str>>();
+        // The lessons for C♭, C♯, and G♭ become the lessons for B, D♭, and F♯, which are already
+        // in the course.
+        assert_eq!(directory_names.len(), 12);
+        assert!(!directory_names.contains(&"lesson_C_flat"));
+        assert!(!directory_names.contains(&"lesson_C_sharp"));
+        assert!(!directory_names.contains(&"lesson_G_flat"));
+        assert!(directory_names.contains(&"lesson_B"));
+        assert!(directory_names.contains(&"lesson_D_flat"));
+        assert!(directory_names.contains(&"lesson_F_sharp"));
+        validate_course_builder(&course_builder)
+    }
+
+    #[test]
+    fn string_subset() -> anyhow::Result<()> {
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .string_subset(vec![3, 4, 5])
+            .build()?;
+        let course_builder = course.course_builder()?;
+        let directory_names = course_builder.lesson_builders[0]
+            .exercise_builders
+            .iter()
+            .map(|exercise_builder| exercise_builder.directory_name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            directory_names,
+            vec!["G_3_string", "B_2_string", "E_1_string"]
+        );
+
+        let course = FretboardExplorationCourse::builder()
+            .course_id(Ustr::from("course"))
+            .directory_name("course")
+            .string_subset(vec![6])
+            .build()?;
+        assert!(course.course_builder().is_err());
+        Ok(())
     }
 }
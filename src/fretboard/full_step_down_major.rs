@@ -0,0 +1,36 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::full_step_down::major_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_full_step_down_major_scale")
+        .scale(GuitarScale::Trane(ScaleType::Major))
+        .tuning(tunings::FULL_STEP_DOWN.to_vec())
+        .tuning_name("Full-Step Down")
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Rock, Genre::Metal])
+        .instructions(indoc! {"
+            Full-step down tuning lowers every string by two semitones. The shapes of the scales
+            and chords are the same as in standard tuning, but the pitches that they produce
+            are lower, so each note is found in different frets.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,131 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType, LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    music::{chromatic_note_name, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::geography_quiz");
+}
+
+/// The number of frets included in the quiz, starting from the open string. The 12th fret is left
+/// out since it has the same note as the open string.
+const FRET_COUNT: u8 = 12;
+
+/// Generates the exercise builders for the lesson. There's one exercise for each fret of each
+/// string, all of them in the same lesson so that they are presented in random order.
+fn generate_exercise_builders(lesson_id: Ustr) -> Vec<ExerciseBuilder> {
+    tunings::STANDARD
+        .iter()
+        .flat_map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let open_note = guitar_string.open_note;
+            (0..FRET_COUNT).map(move |fret| {
+                let exercise_id = format!("string_{}_fret_{}", string_number, fret);
+                ExerciseBuilder {
+                    directory_name: exercise_id.clone(),
+                    asset_builders: vec![
+                        AssetBuilder {
+                            file_name: "front.md".to_string(),
+                            contents: formatdoc! {"
+                                Name the note on fret {}, string {}.
+                            ", fret, string_number},
+                        },
+                        AssetBuilder {
+                            file_name: "back.md".to_string(),
+                            contents: formatdoc! {"
+                                {}
+                            ", chromatic_note_name(semitones(open_note) + fret)},
+                        },
+                    ],
+                    manifest_closure: Box::new(move |m| {
+                        #[allow(clippy::redundant_clone)]
+                        m.clone()
+                            .id(format!("{}::{}", lesson_id, exercise_id))
+                            .name(format!(
+                                "Name the note on fret {}, string {}",
+                                fret, string_number
+                            ))
+                            .clone()
+                    }),
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let lesson_id = Ustr::from(&format!("{}::all_strings", *COURSE_ID));
+    let lesson_builder = LessonBuilder {
+        directory_name: "lesson_all_strings".to_string(),
+        exercise_manifest_template: ExerciseManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .lesson_id(lesson_id)
+            .exercise_type(ExerciseType::Declarative)
+            .exercise_asset(ExerciseAsset::FlashcardAsset {
+                front_path: "front.md".to_string(),
+                back_path: Some("back.md".to_string()),
+            })
+            .clone(),
+        asset_builders: vec![],
+        exercise_builders: generate_exercise_builders(lesson_id),
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(lesson_id)
+                .name("Name the Notes in All Strings".to_string())
+                .description(Some(
+                    "Name the notes in the first twelve frets of all the strings in random order."
+                        .to_string(),
+                ))
+                .dependencies(vec![])
+                .clone()
+        }),
+    };
+
+    CourseBuilder {
+        directory_name: "fretboard_geography_quiz".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Fretboard Geography Quiz".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Name the notes in the fretboard without relying on the order of the strings and \
+                frets."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders: vec![lesson_builder],
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_standard_instruction_asset(indoc! {"
+        All the frets of the fretboard are quizzed in a single lesson, so they come up
+        in random order. Without the previous note as a cue, each note has to be
+        recalled on its own.
+
+        Answer as quickly as possible. If you need to count frets to find the answer,
+        go back to the basic fretboard course to review the string.
+    "})
+}
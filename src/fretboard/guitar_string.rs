@@ -0,0 +1,47 @@
+//! Contains the representation of the strings of the guitar used by the tunings.
+
+use std::fmt::Display;
+
+use trane::data::music::notes::Note;
+
+use crate::music::semitones;
+
+/// A string of the guitar, given by the pitch of the open string and its number. Unlike a `Note`,
+/// the octave tells apart strings tuned to the same note, such as the low and high E strings in
+/// standard tuning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GuitarString {
+    /// The note of the open string.
+    pub open_note: Note,
+
+    /// The octave of the open string in scientific pitch notation (e.g., 2 for the low E string
+    /// in standard tuning).
+    pub octave: i8,
+
+    /// The number of the string, starting with 1 for the highest string.
+    pub string_number: u8,
+}
+
+impl GuitarString {
+    /// Returns a new string with the given open note, octave, and number.
+    pub const fn new(open_note: Note, octave: i8, string_number: u8) -> Self {
+        Self {
+            open_note,
+            octave,
+            string_number,
+        }
+    }
+
+    /// Returns the pitch of the open string as the number of semitones above C-1, which is the
+    /// numbering used by MIDI (e.g., 40 for the low E string in standard tuning).
+    pub fn pitch(&self) -> u8 {
+        ((self.octave + 1) * 12) as u8 + semitones(self.open_note)
+    }
+}
+
+impl Display for GuitarString {
+    /// Writes the pitch of the open string (e.g., E2).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.open_note.to_string(), self.octave)
+    }
+}
@@ -0,0 +1,167 @@
+//! Factory functions for the lessons of the scale courses, so that courses which follow the same
+//! pattern as `FretboardExplorationCourse` generate their lessons in the same way.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use trane::{
+    course_builder::{music::MusicMetadata, ExerciseBuilder, LessonBuilder},
+    data::{music::notes::Note, ExerciseAsset, ExerciseManifestBuilder, ExerciseType},
+};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{
+        fretboard_exploration::{ExerciseOptions, FretboardExplorationCourse},
+        guitar_string::GuitarString,
+    },
+    metadata::{GuitarExerciseMetadata, STRING_KEY},
+    music::GuitarScale,
+};
+
+/// Returns the ID of the lesson for the given key in a scale course.
+pub fn scale_lesson_id(course_id: Ustr, note: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", course_id, note.to_string()))
+}
+
+/// Returns the template used to build the manifests of the exercises in a scale course. The lesson
+/// ID must be set before building an exercise manifest from it.
+pub fn scale_exercise_manifest_template(course_id: Ustr) -> ExerciseManifestBuilder {
+    ExerciseManifestBuilder::default()
+        .course_id(course_id)
+        .exercise_type(ExerciseType::Procedural)
+        .exercise_asset(ExerciseAsset::FlashcardAsset {
+            front_path: "front.md".to_string(),
+            back_path: Some("back.md".to_string()),
+        })
+        .clone()
+}
+
+/// Returns the lesson builder for the lesson on the given key of a scale course with the given
+/// exercises, whose manifests are built from the given template. The lesson depends on the given
+/// lessons, and the key of the lesson is added to the given metadata.
+pub fn make_scale_lesson(
+    course_id: Ustr,
+    note: Note,
+    scale: GuitarScale,
+    exercise_manifest_template: &ExerciseManifestBuilder,
+    exercise_builders: Vec<ExerciseBuilder>,
+    dependencies: Vec<Ustr>,
+    metadata: BTreeMap<String, Vec<String>>,
+) -> LessonBuilder {
+    let lesson_id = scale_lesson_id(course_id, note);
+    let mut metadata = metadata;
+    metadata.insert(MusicMetadata::Key.to_string(), vec![note.to_ascii_string()]);
+
+    LessonBuilder {
+        directory_name: format!("lesson_{}", note.to_ascii_string()),
+        exercise_manifest_template: exercise_manifest_template
+            .clone()
+            .lesson_id(lesson_id)
+            .clone(),
+        asset_builders: vec![],
+        exercise_builders,
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(lesson_id)
+                .name(format!(
+                    "Explore the {} {} Scale in the fretboard",
+                    note.to_string(),
+                    scale,
+                ))
+                .description(Some(format!(
+                    "Explore the notes of the {} {} scale in the fretboard.",
+                    note.to_string(),
+                    scale,
+                )))
+                .dependencies(dependencies.clone())
+                .metadata(Some(metadata.clone()))
+                .clone()
+        }),
+    }
+}
+
+/// Returns the lesson builder for the lesson to explore the scale with the given key in a single
+/// string, using the given options and metadata for its only exercise.
+pub fn make_single_string_scale_lesson(
+    course_id: Ustr,
+    note: Note,
+    scale: GuitarScale,
+    guitar_string: GuitarString,
+    options: ExerciseOptions,
+    exercise_metadata: &GuitarExerciseMetadata,
+    dependencies: Vec<Ustr>,
+) -> Result<LessonBuilder> {
+    let exercise_builders = FretboardExplorationCourse::generate_exercise_builders(
+        course_id,
+        scale,
+        note,
+        &[guitar_string],
+        options,
+        exercise_metadata,
+    )?;
+    let mut metadata = BTreeMap::from([(
+        STRING_KEY.to_string(),
+        vec![guitar_string.open_note.to_ascii_string()],
+    )]);
+    exercise_metadata.add_to(&mut metadata);
+    Ok(make_scale_lesson(
+        course_id,
+        note,
+        scale,
+        &scale_exercise_manifest_template(course_id),
+        exercise_builders,
+        dependencies,
+        metadata,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::{
+        music::{notes::Note, scales::ScaleType},
+        LessonManifestBuilder,
+    };
+    use ustr::Ustr;
+
+    use crate::{
+        fretboard::{
+            fretboard_exploration::ExerciseOptions, lesson_factory::make_single_string_scale_lesson,
+        },
+        metadata::GuitarExerciseMetadata,
+        music::GuitarScale,
+        tunings,
+    };
+
+    #[test]
+    fn single_string_lesson() -> anyhow::Result<()> {
+        let course_id = Ustr::from("course");
+        let previous_lesson = Ustr::from("course::F");
+        let lesson_builder = make_single_string_scale_lesson(
+            course_id,
+            Note::C,
+            GuitarScale::Trane(ScaleType::Major),
+            tunings::STANDARD[0],
+            ExerciseOptions::default(),
+            &GuitarExerciseMetadata::default(),
+            vec![previous_lesson],
+        )?;
+        assert_eq!(lesson_builder.directory_name, "lesson_C");
+        assert_eq!(lesson_builder.exercise_builders.len(), 1);
+        assert_eq!(
+            lesson_builder.exercise_builders[0].directory_name,
+            "E_6_string"
+        );
+
+        let lesson_manifest = (lesson_builder.manifest_closure)(
+            LessonManifestBuilder::default()
+                .course_id(course_id)
+                .clone(),
+        )
+        .build()?;
+        assert_eq!(lesson_manifest.id, Ustr::from("course::C"));
+        assert_eq!(lesson_manifest.dependencies, vec![previous_lesson]);
+        Ok(())
+    }
+}
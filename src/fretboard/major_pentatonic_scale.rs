@@ -0,0 +1,27 @@
+use anyhow::Result;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::major_pentatonic_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_major_pentatonic_scale")
+        .scale(GuitarScale::Trane(ScaleType::MajorPentatonic))
+        .difficulty(Difficulty::Beginner)
+        .genres(vec![Genre::Country, Genre::Rock])
+        .build()?;
+    scale_course.course_builder()
+}
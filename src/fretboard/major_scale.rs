@@ -1,23 +1,140 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
+use indoc::formatdoc;
 use lazy_static::lazy_static;
-use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::music::{notes::Note, scales::ScaleType},
+};
 use ustr::Ustr;
 
-use crate::fretboard::fretboard_exploration::FretboardExplorationCourse;
+use crate::{
+    fretboard::{
+        fretboard_exploration::FretboardExplorationCourse,
+        lesson_factory::{scale_exercise_manifest_template, scale_lesson_id},
+        minor_scale, positions,
+    },
+    metadata::Difficulty,
+    music::GuitarScale,
+    theory::basic_theory,
+};
 
 lazy_static! {
     pub static ref COURSE_ID: Ustr =
         Ustr::from("trane::guitar::fretboard_exploration::major_scale");
 }
 
+/// Returns the ID of the bridge lesson to the given minor key. The ID extends the ID of the lesson
+/// on the same key in the minor scale course, so that the connection between both lessons is
+/// explicit.
+pub fn bridge_lesson_id(minor_key: Note) -> Ustr {
+    Ustr::from(&format!(
+        "{}::bridge",
+        scale_lesson_id(*minor_scale::COURSE_ID, minor_key)
+    ))
+}
+
+/// Generates the bridge lessons from each major key to its relative minor. Each lesson depends on
+/// the lesson of the major key, and the lesson of the minor key in the minor scale course depends on
+/// it in turn.
+fn relative_minor_lessons() -> Result<Vec<LessonBuilder>> {
+    let mut lessons = vec![];
+    for key in Note::all_keys(true) {
+        let minor_key = key.relative_minor()?;
+        let lesson_id = bridge_lesson_id(minor_key);
+        let major_lesson_id = scale_lesson_id(*COURSE_ID, key);
+        let notes = ScaleType::Major
+            .notes(key)?
+            .notes
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let exercise_builder = ExerciseBuilder {
+            directory_name: "relative_minor".to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the relative minor of the key of {} major in the fretboard,
+                        starting from its root.
+                    ", key.to_string()},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The relative minor of {} major is {} minor. Both scales share the same
+                        notes, {}, but the minor scale starts from {}.
+
+                        Once this lesson is mastered, continue with the {} minor lesson of the
+                        minor scale course.
+                    ",
+                    key.to_string(),
+                    minor_key.to_string(),
+                    notes,
+                    minor_key.to_string(),
+                    minor_key.to_string()},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::relative_minor", lesson_id))
+                    .name(format!(
+                        "Play the relative minor of {} major",
+                        key.to_string()
+                    ))
+                    .clone()
+            }),
+        };
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_relative_minor_{}", minor_key.to_ascii_string()),
+            exercise_manifest_template: scale_exercise_manifest_template(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: vec![exercise_builder],
+            manifest_closure: Box::new(move |m| {
+                let metadata = BTreeMap::from([(
+                    MusicMetadata::Key.to_string(),
+                    vec![key.to_ascii_string(), minor_key.to_ascii_string()],
+                )]);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "From {} Major to {} Minor",
+                        key.to_string(),
+                        minor_key.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Find the relative minor of {} major in the fretboard.",
+                        key.to_string()
+                    )))
+                    .dependencies(vec![major_lesson_id])
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
 pub fn course_builder() -> Result<CourseBuilder> {
-    let scale_course = FretboardExplorationCourse {
-        course_id: *COURSE_ID,
-        dependencies: vec![],
-        directory_name: "fretboard_major_scale".to_string(),
-        scale: ScaleType::Major,
-        note_alias: None,
-        tuning: None,
-    };
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*basic_theory::COURSE_ID])
+        .directory_name("fretboard_major_scale")
+        .scale(GuitarScale::Trane(ScaleType::Major))
+        .difficulty(Difficulty::Beginner)
+        .extra_lessons_generator(relative_minor_lessons)
+        .recommended_next(vec![*positions::COURSE_ID])
+        .build()?;
     scale_course.course_builder()
 }
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, minor_scale, note_aliases},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::minor_pentatonic_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*minor_scale::COURSE_ID])
+        .directory_name("fretboard_minor_pentatonic_scale")
+        .scale(GuitarScale::Trane(ScaleType::MinorPentatonic))
+        .note_alias(note_aliases::relative_minor_alias)
+        .difficulty(Difficulty::Beginner)
+        .genres(vec![Genre::Blues, Genre::Rock])
+        .build()?;
+    scale_course.course_builder()
+}
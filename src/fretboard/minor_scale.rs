@@ -3,7 +3,15 @@ use lazy_static::lazy_static;
 use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
 use ustr::Ustr;
 
-use crate::fretboard::fretboard_exploration::FretboardExplorationCourse;
+use crate::{
+    fretboard::{
+        fretboard_exploration::FretboardExplorationCourse, major_scale, minor_pentatonic_scale,
+        note_aliases,
+    },
+    metadata::Difficulty,
+    music::GuitarScale,
+    theory::basic_theory,
+};
 
 lazy_static! {
     pub static ref COURSE_ID: Ustr =
@@ -11,13 +19,15 @@ lazy_static! {
 }
 
 pub fn course_builder() -> Result<CourseBuilder> {
-    let scale_course = FretboardExplorationCourse {
-        course_id: *COURSE_ID,
-        dependencies: vec![],
-        directory_name: "fretboard_minor_scale".to_string(),
-        scale: ScaleType::Minor,
-        note_alias: Some(|note| note.relative_minor()),
-        tuning: None,
-    };
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*basic_theory::COURSE_ID])
+        .directory_name("fretboard_minor_scale")
+        .scale(GuitarScale::Trane(ScaleType::Minor))
+        .note_alias(note_aliases::relative_minor_alias)
+        .lesson_dependencies(|note| vec![major_scale::bridge_lesson_id(note)])
+        .difficulty(Difficulty::Intermediate)
+        .recommended_next(vec![*minor_pentatonic_scale::COURSE_ID])
+        .build()?;
     scale_course.course_builder()
 }
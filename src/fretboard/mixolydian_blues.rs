@@ -0,0 +1,43 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::mixolydian},
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+    theory::pentatonic_blues_transition,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::mixolydian_blues");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![
+            *mixolydian::COURSE_ID,
+            *pentatonic_blues_transition::COURSE_ID,
+        ])
+        .directory_name("fretboard_mixolydian_blues_scale")
+        .scale(GuitarScale::Custom(CustomScale::MixolydianBlues))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Blues, Genre::Rock])
+        .instructions(indoc! {"
+            The Mixolydian blues scale adds the ♭3 of the blues scale to the Mixolydian mode,
+            so it's formed by the degrees 1, 2, ♭3, 3, 4, 5, 6, and ♭7. It's played over a
+            vamp on a single dominant 7th chord, such as the I7 of a blues. The 3 and the ♭7
+            outline the chord, while the ♭3 is a blue note which sounds best when it's bent
+            or slid into the major 3rd.
+
+            This mix of major and minor sounds is heard in the solos of many blues-rock
+            players. Try moving between the two thirds to hear the tension and release they
+            create over the chord.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,34 @@
+//! Courses to explore the seven modes of the major scale in the fretboard. The courses are chained
+//! in the order of the degree on which each mode starts, so that each mode is introduced by
+//! comparing it with the previous one. The Ionian course depends on the major scale course. The
+//! modes of other scales, such as the Lydian dominant and the altered modes of the melodic minor
+//! scale or the Aeolian dominant mode of the harmonic minor scale, follow them.
+pub mod aeolian;
+pub mod aeolian_dominant;
+pub mod altered_scale;
+pub mod dorian;
+pub mod ionian;
+pub mod locrian;
+pub mod lydian;
+pub mod lydian_dominant;
+pub mod mixolydian;
+pub mod phrygian;
+
+use anyhow::Result;
+use trane::course_builder::CourseBuilder;
+
+/// Returns the course builders for all the modes.
+pub fn course_builders() -> Result<Vec<CourseBuilder>> {
+    Ok(vec![
+        ionian::course_builder()?,
+        dorian::course_builder()?,
+        phrygian::course_builder()?,
+        lydian::course_builder()?,
+        mixolydian::course_builder()?,
+        aeolian::course_builder()?,
+        locrian::course_builder()?,
+        lydian_dominant::course_builder()?,
+        altered_scale::course_builder()?,
+        aeolian_dominant::course_builder()?,
+    ])
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::mixolydian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::aeolian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*mixolydian::COURSE_ID])
+        .directory_name("fretboard_modes_aeolian")
+        .scale(GuitarScale::Mode(Mode::Aeolian))
+        .note_alias(|key| Mode::Aeolian.tonic(key))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Rock, Genre::Pop])
+        .instructions(indoc! {"
+            The Aeolian mode starts on the 6th degree of the major scale and it's the same
+            as the natural minor scale. Compared to Mixolydian, its 3rd and 6th are lowered.
+            Its characteristic interval is the minor 6th, which sets it apart from Dorian.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{
+        double_harmonic_scale,
+        fretboard_exploration::{compose_note_aliases, FretboardExplorationCourse},
+        modes::phrygian,
+        note_aliases,
+    },
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::aeolian_dominant");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![
+            *phrygian::COURSE_ID,
+            *double_harmonic_scale::COURSE_ID,
+        ])
+        .directory_name("fretboard_modes_aeolian_dominant")
+        .scale(GuitarScale::Custom(CustomScale::AeolianDominant))
+        .note_alias(compose_note_aliases(
+            note_aliases::relative_minor_alias,
+            note_aliases::up_perfect_fifth_alias,
+        ))
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Flamenco])
+        .instructions(indoc! {"
+            The Aeolian dominant mode, also known as Spanish Phrygian, is the 5th mode of the
+            harmonic minor scale, so it starts a perfect fifth above the tonic of its parent
+            scale. It's the Phrygian mode with a major 3rd. The ♭2 and the major 3rd are its
+            defining intervals, and together they give it the sound of flamenco music.
+
+            Use a Phrygian cadence as a backing vamp, such as Am - G - F - E when playing the E
+            Aeolian dominant mode, and resolve your phrases to the root over the last chord.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fretboard::modes::aeolian_dominant::course_builder, in_memory::build_course_in_memory,
+    };
+
+    /// Verifies that the course contains a lesson for each key in the circle of fifths, with an
+    /// exercise for each string in standard tuning.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 90);
+        Ok(())
+    }
+}
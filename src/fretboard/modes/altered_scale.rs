@@ -0,0 +1,40 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{
+        fretboard_exploration::FretboardExplorationCourse,
+        modes::{locrian, lydian_dominant},
+        note_aliases,
+    },
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::altered");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*lydian_dominant::COURSE_ID, *locrian::COURSE_ID])
+        .directory_name("fretboard_modes_altered")
+        .scale(GuitarScale::Custom(CustomScale::Altered))
+        .note_alias(note_aliases::down_half_step_alias)
+        .difficulty(Difficulty::Expert)
+        .genres(vec![Genre::Jazz])
+        .instructions(indoc! {"
+            The altered scale, also called the Superlocrian mode, is the 7th mode of the melodic
+            minor scale, so it starts a half step below the tonic of its parent scale. It keeps
+            the root, the 3rd, and the ♭7 of a dominant chord and alters every other tone: the
+            ♭9, the ♯9, the ♭5/♯11, and the ♭13. Play it over altered dominant chords that
+            resolve to the tonic, where its tension is strongest.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::ionian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::dorian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*ionian::COURSE_ID])
+        .directory_name("fretboard_modes_dorian")
+        .scale(GuitarScale::Mode(Mode::Dorian))
+        .note_alias(|key| Mode::Dorian.tonic(key))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Jazz, Genre::Rock])
+        .instructions(indoc! {"
+            The Dorian mode starts on the 2nd degree of the major scale. Compared to Ionian,
+            its 3rd and 7th are lowered, which makes it a minor mode. Its characteristic
+            interval is the major 6th, which sets it apart from the natural minor scale.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::Difficulty,
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::ionian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_modes_ionian")
+        .scale(GuitarScale::Mode(Mode::Ionian))
+        .difficulty(Difficulty::Beginner)
+        .instructions(indoc! {"
+            Ionian = major scale. The Ionian mode contains the same notes as the major scale
+            of the same root, and it's the modal reference point from which the rest of the
+            modes are derived: each mode starts on a different degree of the Ionian mode and
+            is described by how its intervals differ from it.
+
+            Its characteristic intervals are the major 3rd and the major 7th, which give it
+            its bright and resolved sound.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
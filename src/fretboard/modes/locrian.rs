@@ -0,0 +1,34 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::aeolian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::locrian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*aeolian::COURSE_ID])
+        .directory_name("fretboard_modes_locrian")
+        .scale(GuitarScale::Mode(Mode::Locrian))
+        .note_alias(|key| Mode::Locrian.tonic(key))
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Jazz, Genre::Metal])
+        .instructions(indoc! {"
+            The Locrian mode starts on the 7th degree of the major scale. Compared to
+            Aeolian, its 2nd and 5th are also lowered. Its characteristic interval is the
+            diminished 5th, which makes its tonic chord diminished and its sound unstable.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::phrygian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::lydian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*phrygian::COURSE_ID])
+        .directory_name("fretboard_modes_lydian")
+        .scale(GuitarScale::Mode(Mode::Lydian))
+        .note_alias(|key| Mode::Lydian.tonic(key))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Jazz])
+        .instructions(indoc! {"
+            The Lydian mode starts on the 4th degree of the major scale. Unlike Phrygian,
+            which is the darkest of the minor modes, Lydian is the brightest of the major
+            modes. Its characteristic interval is the augmented 4th, which is the only
+            difference with Ionian.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
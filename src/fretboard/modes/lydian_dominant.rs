@@ -0,0 +1,40 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{
+        fretboard_exploration::FretboardExplorationCourse,
+        modes::{lydian, mixolydian},
+        note_aliases,
+    },
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::lydian_dominant");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*lydian::COURSE_ID, *mixolydian::COURSE_ID])
+        .directory_name("fretboard_modes_lydian_dominant")
+        .scale(GuitarScale::Custom(CustomScale::LydianDominant))
+        .note_alias(note_aliases::up_perfect_fourth_alias)
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Jazz])
+        .instructions(indoc! {"
+            The Lydian dominant mode is the 4th mode of the melodic minor scale, so it starts a
+            perfect fourth above the tonic of its parent scale. It can be seen as Mixolydian
+            with a raised 4th, or as Lydian with a lowered 7th. The ♯4 and the ♭7 are its most
+            characteristic notes, and together they make it the go-to scale over dominant 7♯11
+            chords.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
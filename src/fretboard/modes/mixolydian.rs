@@ -0,0 +1,34 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::lydian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::mixolydian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*lydian::COURSE_ID])
+        .directory_name("fretboard_modes_mixolydian")
+        .scale(GuitarScale::Mode(Mode::Mixolydian))
+        .note_alias(|key| Mode::Mixolydian.tonic(key))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Blues, Genre::Rock])
+        .instructions(indoc! {"
+            The Mixolydian mode starts on the 5th degree of the major scale. Compared to
+            Lydian, its 4th is perfect and its 7th is lowered. Its characteristic interval
+            is the minor 7th over a major 3rd, which forms the dominant 7th chord.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
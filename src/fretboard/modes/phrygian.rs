@@ -0,0 +1,34 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, modes::dorian},
+    metadata::{Difficulty, Genre},
+    music::{GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::modes::phrygian");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*dorian::COURSE_ID])
+        .directory_name("fretboard_modes_phrygian")
+        .scale(GuitarScale::Mode(Mode::Phrygian))
+        .note_alias(|key| Mode::Phrygian.tonic(key))
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Metal])
+        .instructions(indoc! {"
+            The Phrygian mode starts on the 3rd degree of the major scale. Compared to
+            Dorian, its 2nd and 6th are also lowered. Its characteristic interval is the
+            minor 2nd above the tonic, which gives it its dark and tense sound.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,127 @@
+//! Common note aliases to use with `FretboardExplorationCourse`. Each alias takes the key of the
+//! lesson in the circle of fifths and returns the note on which the lesson is based. They can be
+//! combined with `compose_note_aliases`.
+
+use anyhow::{anyhow, Result};
+use trane::data::music::notes::{Accidental, Note};
+
+use crate::music::{note_from_semitones, semitones, semitones_up, NATURAL_NOTES};
+
+/// Returns the note the given number of letters and semitones above the note. Returns an error
+/// if the note would need a double accidental.
+fn note_above(note: Note, letters: usize, interval: u8) -> Result<Note> {
+    let letter_index = NATURAL_NOTES
+        .iter()
+        .position(|letter| *letter == note.0)
+        .unwrap_or_default();
+    let letter = NATURAL_NOTES[(letter_index + letters) % 7];
+    let pitch = note_from_semitones(semitones(note) + interval);
+    match semitones_up(Note(letter, Accidental::Natural), pitch) {
+        0 => Ok(Note(letter, Accidental::Natural)),
+        1 => Ok(Note(letter, Accidental::Sharp)),
+        11 => Ok(Note(letter, Accidental::Flat)),
+        _ => Err(anyhow!(
+            "the note {} semitones above {} requires a double accidental",
+            interval,
+            note.to_string()
+        )),
+    }
+}
+
+/// Verifies that the alias can be applied to all the keys in the circle of fifths, including the
+/// enharmonic keys. The error lists every key for which the alias fails, along with the reason.
+pub fn validate_note_alias(alias: &dyn Fn(Note) -> Result<Note>) -> Result<()> {
+    let failures = Note::all_keys(true)
+        .into_iter()
+        .filter_map(|key| {
+            alias(key)
+                .err()
+                .map(|error| format!("{} ({})", key.to_string(), error))
+        })
+        .collect::<Vec<String>>();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "the note alias failed for the following keys: {}",
+            failures.join(", ")
+        ))
+    }
+}
+
+/// Returns the key unchanged.
+pub fn no_alias(note: Note) -> Result<Note> {
+    Ok(note)
+}
+
+/// Returns the relative minor of the key. Used by the courses on minor scales so that the lessons
+/// follow the number of sharps or flats in the scale.
+pub fn relative_minor_alias(note: Note) -> Result<Note> {
+    note.relative_minor()
+}
+
+/// Returns the relative major of the key.
+#[allow(dead_code)]
+pub fn relative_major_alias(note: Note) -> Result<Note> {
+    note.relative_major()
+}
+
+/// Returns the note a perfect fifth above the key.
+pub fn up_perfect_fifth_alias(note: Note) -> Result<Note> {
+    note_above(note, 4, 7)
+}
+
+/// Returns the note a perfect fourth above the key. Used by the courses on the fourth mode of a
+/// scale, so that each lesson is based on the key of the parent scale.
+pub fn up_perfect_fourth_alias(note: Note) -> Result<Note> {
+    note_above(note, 3, 5)
+}
+
+/// Returns the note a half step below the key, spelled as its leading tone. Used by the course on
+/// the seventh mode of the melodic minor scale, so that each lesson is based on the key of the
+/// parent scale.
+pub fn down_half_step_alias(note: Note) -> Result<Note> {
+    note_above(note, 6, 11)
+}
+
+/// Returns the note a perfect fourth below the key, which has the same name as the note a perfect
+/// fifth above it.
+#[allow(dead_code)]
+pub fn down_perfect_fourth_alias(note: Note) -> Result<Note> {
+    up_perfect_fifth_alias(note)
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::notes::Note;
+
+    use crate::fretboard::note_aliases::{
+        down_half_step_alias, down_perfect_fourth_alias, no_alias, relative_major_alias,
+        relative_minor_alias, up_perfect_fifth_alias, up_perfect_fourth_alias, validate_note_alias,
+    };
+
+    #[test]
+    fn aliases() -> anyhow::Result<()> {
+        assert_eq!(no_alias(Note::G)?, Note::G);
+        assert_eq!(relative_minor_alias(Note::C)?, Note::A);
+        assert_eq!(relative_major_alias(Note::E)?, Note::G);
+        assert_eq!(up_perfect_fifth_alias(Note::B)?, Note::F_SHARP);
+        assert_eq!(up_perfect_fifth_alias(Note::B_FLAT)?, Note::F);
+        assert_eq!(down_perfect_fourth_alias(Note::C_SHARP)?, Note::G_SHARP);
+        assert_eq!(up_perfect_fourth_alias(Note::C)?, Note::F);
+        assert_eq!(up_perfect_fourth_alias(Note::G_FLAT)?, Note::C_FLAT);
+        assert_eq!(down_half_step_alias(Note::C)?, Note::B);
+        assert_eq!(down_half_step_alias(Note::D_FLAT)?, Note::C);
+        Ok(())
+    }
+
+    #[test]
+    fn validate_aliases() {
+        assert!(validate_note_alias(&relative_minor_alias).is_ok());
+        assert!(validate_note_alias(&up_perfect_fifth_alias).is_ok());
+
+        // The relative major of C♭ would be E𝄫.
+        let error = validate_note_alias(&relative_major_alias).unwrap_err();
+        assert!(error.to_string().contains("C♭"));
+    }
+}
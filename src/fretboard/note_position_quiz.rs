@@ -0,0 +1,157 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType, LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{basic_guitar_fretboard, guitar_string::GuitarString},
+    music::{chromatic_note_name, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::note_position_quiz");
+}
+
+/// The highest fret included in the quiz.
+const LAST_FRET: u8 = 12;
+
+/// Returns the name of the string used in the cards. Strings tuned to the same note as another
+/// string are told apart by whether they are the lowest or the highest of them (e.g., low E).
+fn string_name(guitar_string: &GuitarString) -> String {
+    let same_note = tunings::STANDARD
+        .iter()
+        .filter(|other| other.open_note == guitar_string.open_note)
+        .collect::<Vec<&GuitarString>>();
+    if same_note.len() == 1 {
+        guitar_string.open_note.to_string()
+    } else if same_note
+        .iter()
+        .all(|other| other.octave >= guitar_string.octave)
+    {
+        format!("low {}", guitar_string.open_note.to_string())
+    } else {
+        format!("high {}", guitar_string.open_note.to_string())
+    }
+}
+
+/// Generates the exercise builders for the lesson of the given string. There's one exercise for
+/// each fret.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    guitar_string: &GuitarString,
+) -> Vec<ExerciseBuilder> {
+    let string_number = guitar_string.string_number;
+    let open_note = guitar_string.open_note;
+    let string_name = string_name(guitar_string);
+    (0..=LAST_FRET)
+        .map(|fret| {
+            let note_name = chromatic_note_name(semitones(open_note) + fret);
+            ExerciseBuilder {
+                directory_name: format!("fret_{}", fret),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            What note is on fret {} of string {} ({})?
+                        ", fret, string_number, string_name},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The note on fret {} of string {} is {}.
+                        ", fret, string_number, note_name},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::fret_{}", lesson_id, fret))
+                        .name(format!(
+                            "Name the note on fret {} of string {}",
+                            fret, string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let lesson_builders = tunings::STANDARD
+        .iter()
+        .rev()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let lesson_id = Ustr::from(&format!("{}::string_{}", *COURSE_ID, string_number));
+
+            LessonBuilder {
+                directory_name: format!("lesson_string_{}", string_number),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Declarative)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, guitar_string),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("Name the Notes of String {}", string_number))
+                        .description(Some(format!(
+                            "Name the note on each of the first {} frets of string {}.",
+                            LAST_FRET, string_number
+                        )))
+                        .dependencies(vec![])
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "note_position_quiz".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Fretboard Note Position Quiz".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Name the note found on a given fret and string of the guitar.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_standard_instruction_asset(indoc! {"
+        The basic fretboard course asks you to find a note in the fretboard. This course
+        tests the same knowledge in reverse: given a fret and a string, name the note
+        found there.
+
+        Try to answer without counting the frets from the open string. Instead, use the
+        notes you already know, such as the natural notes or the markers on the
+        fretboard, as a reference.
+    "})
+}
@@ -0,0 +1,2 @@
+//! Courses to explore the fretboard in Open E tuning.
+pub mod major_scale;
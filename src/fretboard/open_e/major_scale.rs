@@ -0,0 +1,44 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::open_e::major_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_open_e_major_scale")
+        .scale(GuitarScale::Trane(ScaleType::Major))
+        .tuning(tunings::OPEN_E.to_vec())
+        .tuning_name("Open E")
+        .describe_open_strings(true)
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Blues, Genre::Rock])
+        .instructions(indoc! {"
+            Open E tuning raises the fifth, fourth, and third strings from standard tuning,
+            so that the open strings form an E major chord. Like Open G, it's a favorite
+            tuning for slide guitar.
+
+            Raising the strings puts extra tension on them and on the neck of the guitar.
+            Some players prefer to tune to Open D instead and place a capo on the 2nd fret,
+            which produces the same notes.
+
+            The back of each card lists the open strings which are part of the scale. In the
+            key of E, they are all tones of the E major chord. Let them ring along with the
+            fretted notes.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
@@ -0,0 +1,2 @@
+//! Courses to explore the fretboard in Open G tuning.
+pub mod major_scale;
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::{course_builder::CourseBuilder, data::music::scales::ScaleType};
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::GuitarScale,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::open_g::major_scale");
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_open_g_major_scale")
+        .scale(GuitarScale::Trane(ScaleType::Major))
+        .tuning(tunings::OPEN_G.to_vec())
+        .tuning_name("Open G")
+        .describe_open_strings(true)
+        .difficulty(Difficulty::Intermediate)
+        .genres(vec![Genre::Blues, Genre::Rock])
+        .instructions(indoc! {"
+            Open G tuning lowers the sixth, fifth, and first strings by a whole step from
+            standard tuning, so that the open strings form a G major chord. A barre across all
+            the strings at any fret produces a major chord, which makes this the classic tuning
+            for slide guitar.
+
+            The back of each card lists the open strings which are part of the scale. Let
+            them ring along with the fretted notes.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
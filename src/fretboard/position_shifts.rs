@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{
+        major_scale,
+        positions::{self, start_fret, POSITION_WIDTH},
+    },
+    music::{circle_fifths_keys, semitones},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::position_shifts");
+}
+
+/// The absolute pitch in semitones of the 1st string in standard tuning, in which the change of
+/// position happens.
+const FIRST_STRING_PITCH: u8 = 64;
+
+/// Returns the ID of the lesson for the shift from the given position to the next one.
+fn lesson_id(key: Note, position: usize) -> Ustr {
+    Ustr::from(&format!(
+        "{}::{}::position_{}_to_{}",
+        *COURSE_ID,
+        key.to_string(),
+        position,
+        position + 1
+    ))
+}
+
+/// Returns the pivot note and its fret in the 1st string for the shift from the position starting
+/// at `from_fret` to the one starting at `to_fret`. The pivot is the highest note of the scale
+/// that can be reached from both positions.
+fn pivot(scale_notes: &[Note], from_fret: u8, to_fret: u8) -> Result<(Note, u8)> {
+    (to_fret..from_fret + POSITION_WIDTH)
+        .rev()
+        .find_map(|fret| {
+            scale_notes
+                .iter()
+                .find(|note| semitones(**note) == (FIRST_STRING_PITCH + fret) % 12)
+                .map(|note| (*note, fret))
+        })
+        .ok_or_else(|| anyhow!("no pivot note between frets {} and {}", from_fret, to_fret))
+}
+
+/// Generates the exercise builders for the lesson on the shift from the given position to the next
+/// one. The shift is practiced in both directions.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    key: Note,
+    position: usize,
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let from_fret = start_fret(&scale_notes, position);
+    // The next position wraps around to the start of the neck when it would start past the 12th
+    // fret, so it's played an octave higher to keep both positions next to each other.
+    let mut to_fret = start_fret(&scale_notes, position + 1);
+    if to_fret < from_fret {
+        to_fret += 12;
+    }
+    let (pivot_note, pivot_fret) = pivot(&scale_notes, from_fret, to_fret)?;
+
+    let directions = [
+        ("ascending", position, from_fret, position + 1, to_fret),
+        ("descending", position + 1, to_fret, position, from_fret),
+    ];
+    Ok(directions
+        .into_iter()
+        .map(
+            |(direction, first_position, first_fret, second_position, second_fret)| {
+                ExerciseBuilder {
+                    directory_name: direction.to_string(),
+                    asset_builders: vec![
+                        AssetBuilder {
+                            file_name: "front.md".to_string(),
+                            contents: formatdoc! {"
+                                Play the {} major scale ascending in position {} (frets {} to
+                                {}). Shift into position {} (frets {} to {}) in the 1st string
+                                and play the scale descending from there.
+                            ",
+                            key.to_string(),
+                            first_position,
+                            first_fret,
+                            first_fret + POSITION_WIDTH - 1,
+                            second_position,
+                            second_fret,
+                            second_fret + POSITION_WIDTH - 1},
+                        },
+                        AssetBuilder {
+                            file_name: "back.md".to_string(),
+                            contents: formatdoc! {"
+                                The pivot note is {}, found in fret {} of the 1st string. It's
+                                shared by both positions.
+
+                                Play it with finger {} while in position {}, then move the hand
+                                so that the same fret falls under finger {} and continue in
+                                position {}.
+                            ",
+                            pivot_note.to_string(),
+                            pivot_fret,
+                            pivot_fret - first_fret + 1,
+                            first_position,
+                            pivot_fret - second_fret + 1,
+                            second_position},
+                        },
+                    ],
+                    manifest_closure: Box::new(move |m| {
+                        #[allow(clippy::redundant_clone)]
+                        m.clone()
+                            .id(format!("{}::{}", lesson_id, direction))
+                            .name(format!(
+                                "Shift from position {} to position {} in {} major",
+                                first_position,
+                                second_position,
+                                key.to_string()
+                            ))
+                            .clone()
+                    }),
+                }
+            },
+        )
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for (key, previous_key) in circle_fifths_keys() {
+        for position in 1..7 {
+            let lesson_id = lesson_id(key, position);
+            let dependencies = if position == 1 {
+                previous_key
+                    .map(|previous_key| self::lesson_id(previous_key, 1))
+                    .into_iter()
+                    .collect::<Vec<Ustr>>()
+            } else {
+                vec![self::lesson_id(key, position - 1)]
+            };
+
+            lesson_builders.push(LessonBuilder {
+                directory_name: format!(
+                    "lesson_{}_position_{}_to_{}",
+                    key.to_ascii_string(),
+                    position,
+                    position + 1
+                ),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, key, position)?,
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!(
+                            "{} Major from Position {} to Position {}",
+                            key.to_string(),
+                            position,
+                            position + 1
+                        ))
+                        .description(Some(format!(
+                            "Move between positions {} and {} while playing the {} major scale.",
+                            position,
+                            position + 1,
+                            key.to_string()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .metadata(Some(BTreeMap::from([(
+                            MusicMetadata::Key.to_string(),
+                            vec![key.to_ascii_string()],
+                        )])))
+                        .clone()
+                }),
+            });
+        }
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "fretboard_position_shifts".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Shifting Between Positions".to_string(),
+            dependencies: vec![*positions::COURSE_ID, *major_scale::COURSE_ID],
+            description: Some(
+                "Connect the positions of the major scale to move freely across the neck."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["scales"])
+    .with_scale_metadata(ScaleType::Major)
+    .with_standard_instruction_asset(indoc! {"
+        Each lesson in this course connects two adjacent positions of the major scale.
+        The change of position happens at a pivot note in the 1st string, which can be
+        played from both positions.
+
+        Make the shift without stopping the flow of the scale. The thumb should move
+        along with the hand so that it stays behind the fingers in the new position.
+    "}))
+}
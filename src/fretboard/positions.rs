@@ -0,0 +1,292 @@
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use std::collections::BTreeMap;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::major_scale,
+    music::{circle_fifths_keys, semitones, semitones_up},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard_exploration::positions");
+}
+
+/// The number of frets covered by the fretting hand in each position.
+pub const POSITION_WIDTH: u8 = 4;
+
+/// Returns the fret at which the given position (starting at 1) of the scale starts. Each position
+/// starts at the fret of the corresponding degree of the scale in the 6th string.
+pub fn start_fret(scale_notes: &[Note], position: usize) -> u8 {
+    semitones_up(tunings::STANDARD[0].open_note, scale_notes[position - 1])
+}
+
+/// A course to play a given scale in each of the seven positions of the neck.
+pub struct PositionPlayingCourse {
+    /// The ID of the course.
+    pub course_id: Ustr,
+
+    /// The dependencies for this course.
+    pub dependencies: Vec<Ustr>,
+
+    /// The name of the directory under which the course will be stored.
+    pub directory_name: String,
+
+    /// The scale type that this course is about.
+    pub scale: ScaleType,
+
+    /// An optional function used to generate the name of the note for the lesson from each major
+    /// key in the circle of fifths.
+    pub note_alias: Option<fn(Note) -> Result<Note>>,
+}
+
+impl PositionPlayingCourse {
+    /// Returns the ID of the lesson for the given key and position.
+    fn lesson_id(course_id: Ustr, note: Note, position: usize) -> Ustr {
+        Ustr::from(&format!(
+            "{}::{}::position_{}",
+            course_id,
+            note.to_string(),
+            position
+        ))
+    }
+
+    /// Returns the answer for the given string and position. The position starts at the given fret
+    /// and covers `POSITION_WIDTH` frets. A note of the scale which falls between this string and
+    /// the next and can't be reached inside the position requires a stretch of the little finger.
+    fn string_answer(string_index: usize, start_fret: u8, scale_notes: &[Note]) -> String {
+        let open_pitch = tunings::STANDARD[string_index].pitch();
+        // Returns the note of the scale with the given pitch, if any, so that it's spelled as in
+        // the scale.
+        let scale_note = |pitch: u8| {
+            scale_notes
+                .iter()
+                .find(|note| semitones(**note) == pitch % 12)
+                .copied()
+        };
+
+        let notes = (start_fret..start_fret + POSITION_WIDTH)
+            .filter_map(|fret| {
+                scale_note(open_pitch + fret)
+                    .map(|note| format!("{} (fret {})", note.to_string(), fret))
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let stretch_fret = start_fret + POSITION_WIDTH;
+        let stretch_pitch = open_pitch + stretch_fret;
+        let stretch_note = match tunings::STANDARD.get(string_index + 1) {
+            Some(next_string) if next_string.pitch() + start_fret > stretch_pitch => {
+                scale_note(stretch_pitch)
+            }
+            _ => None,
+        };
+        let stretch = match stretch_note {
+            Some(note) => format!(
+                "Stretch the little finger to fret {} to play {}, which can't be reached in \
+                the next string without leaving the position.",
+                stretch_fret,
+                note.to_string()
+            ),
+            None => "No stretches are needed in this string.".to_string(),
+        };
+
+        formatdoc! {"
+            The notes within the position are: {}.
+
+            {}
+        ", notes, stretch}
+    }
+
+    /// Generates the exercise builders for the lesson with the given key and position.
+    fn generate_exercise_builders(
+        lesson_id: Ustr,
+        scale: ScaleType,
+        note: Note,
+        position: usize,
+    ) -> Result<Vec<ExerciseBuilder>> {
+        let scale_notes = scale.notes(note)?.notes;
+        let start_fret = start_fret(&scale_notes, position);
+        let end_fret = start_fret + POSITION_WIDTH - 1;
+
+        Ok(tunings::STANDARD
+            .iter()
+            .enumerate()
+            .map(|(string_index, guitar_string)| {
+                let string_number = guitar_string.string_number;
+                ExerciseBuilder {
+                    directory_name: format!("string_{}", string_number),
+                    asset_builders: vec![
+                        AssetBuilder {
+                            file_name: "front.md".to_string(),
+                            contents: formatdoc! {"
+                                Play the {} {} scale in position {} (frets {} to {}) in string {}
+                                without moving the fretting hand.
+                            ",
+                            note.to_string(),
+                            scale.to_string(),
+                            position,
+                            start_fret,
+                            end_fret,
+                            string_number},
+                        },
+                        AssetBuilder {
+                            file_name: "back.md".to_string(),
+                            contents: Self::string_answer(string_index, start_fret, &scale_notes),
+                        },
+                    ],
+                    manifest_closure: Box::new(move |m| {
+                        #[allow(clippy::redundant_clone)]
+                        m.clone()
+                            .id(format!("{}::string_{}", lesson_id, string_number))
+                            .name(format!(
+                                "Play the {} {} scale in position {} in string {}",
+                                note.to_string(),
+                                scale.to_string(),
+                                position,
+                                string_number
+                            ))
+                            .clone()
+                    }),
+                }
+            })
+            .collect())
+    }
+
+    /// Generates the lesson builders for all the positions in the given key. The first position
+    /// depends on the first position of the previous key in the circle of fifths, and every other
+    /// position depends on the position before it.
+    fn generate_lesson_builders(
+        &self,
+        note: Note,
+        previous_note: Option<Note>,
+    ) -> Result<Vec<LessonBuilder>> {
+        let course_id = self.course_id;
+        let scale = self.scale;
+        let mut lessons = vec![];
+        for position in 1..=7 {
+            let lesson_id = Self::lesson_id(course_id, note, position);
+            let deps = if position == 1 {
+                previous_note
+                    .map(|previous_note| Self::lesson_id(course_id, previous_note, 1))
+                    .into_iter()
+                    .collect::<Vec<Ustr>>()
+            } else {
+                vec![Self::lesson_id(course_id, note, position - 1)]
+            };
+
+            lessons.push(LessonBuilder {
+                directory_name: format!("lesson_{}_position_{}", note.to_ascii_string(), position),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: Self::generate_exercise_builders(
+                    lesson_id, scale, note, position,
+                )?,
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!(
+                            "The {} {} Scale in Position {}",
+                            note.to_string(),
+                            scale.to_string(),
+                            position
+                        ))
+                        .description(Some(format!(
+                            "Play the {} {} scale in position {} of the neck.",
+                            note.to_string(),
+                            scale.to_string(),
+                            position
+                        )))
+                        .dependencies(deps.clone())
+                        .metadata(Some(BTreeMap::from([(
+                            MusicMetadata::Key.to_string(),
+                            vec![note.to_ascii_string()],
+                        )])))
+                        .clone()
+                }),
+            });
+        }
+        Ok(lessons)
+    }
+
+    /// Generates the course builder for the position playing course.
+    pub fn course_builder(&self) -> Result<CourseBuilder> {
+        let mut lesson_builders = vec![];
+        for (key, previous_key) in circle_fifths_keys() {
+            let (note, previous_note) = match self.note_alias {
+                None => (key, previous_key),
+                Some(note_alias) => (note_alias(key)?, previous_key.map(note_alias).transpose()?),
+            };
+            lesson_builders.extend(self.generate_lesson_builders(note, previous_note)?);
+        }
+
+        Ok(CourseBuilder {
+            directory_name: self.directory_name.clone(),
+            course_manifest: CourseManifest {
+                id: self.course_id,
+                name: format!("Play the {} Scale in Position", self.scale.to_string()),
+                dependencies: self.dependencies.clone(),
+                description: Some(format!(
+                    "Play the {} scale in the seven positions of the neck for all keys.",
+                    self.scale.to_string()
+                )),
+                authors: Some(vec![AUTHORS.to_string()]),
+                metadata: None,
+                course_material: None,
+                course_instructions: None,
+                generator_config: None,
+            },
+            asset_builders: vec![],
+            lesson_builders,
+            lesson_manifest_template: LessonManifestBuilder::default()
+                .course_id(self.course_id)
+                .clone(),
+        }
+        .with_instrument_metadata()
+        .with_fretboard_skill_metadata()
+        .with_concept_metadata(&["scales"])
+        .with_scale_metadata(self.scale)
+        .with_standard_instruction_asset(indoc! {"
+            Playing in position means keeping the fretting hand fixed over a window of
+            four frets, with one finger assigned to each fret. Each position starts on
+            the fret of one of the degrees of the scale in the 6th string.
+
+            Keep the hand in place and only stretch the little finger when a
+            note can't be reached otherwise.
+        "}))
+    }
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let position_course = PositionPlayingCourse {
+        course_id: *COURSE_ID,
+        dependencies: vec![*major_scale::COURSE_ID],
+        directory_name: "fretboard_positions".to_string(),
+        scale: ScaleType::Major,
+        note_alias: None,
+    };
+    position_course.course_builder()
+}
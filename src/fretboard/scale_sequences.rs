@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, minor_scale, note_aliases},
+    music::{circle_fifths_keys, semitones_up},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::scale_sequences");
+}
+
+/// A melodic sequence applied to the scale, made of overlapping groups of notes.
+struct Sequence {
+    /// The name of the sequence.
+    name: &'static str,
+
+    /// The ID used in the exercise IDs and directory names.
+    id: &'static str,
+
+    /// The offsets in the scale of the notes in each group from the first note of the group.
+    offsets: &'static [usize],
+}
+
+/// The sequences taught in each lesson.
+static SEQUENCES: [Sequence; 3] = [
+    Sequence {
+        name: "Diatonic 3rds",
+        id: "diatonic_3rds",
+        offsets: &[0, 2],
+    },
+    Sequence {
+        name: "Diatonic 4ths",
+        id: "diatonic_4ths",
+        offsets: &[0, 3],
+    },
+    Sequence {
+        name: "Ascending groups of four",
+        id: "groups_of_four",
+        offsets: &[0, 1, 2, 3],
+    },
+];
+
+/// The number of notes of the scale played in each of the two lowest strings.
+const NOTES_PER_STRING: usize = 3;
+
+/// Returns the string, fret, and note of the first notes of the scale in the 6th and 5th strings,
+/// starting with the tonic in the 6th string and playing three notes in each string.
+fn lowest_string_notes(scale_notes: &[Note]) -> Vec<(usize, u8, Note)> {
+    let mut fret = semitones_up(Note::E, scale_notes[0]);
+    let mut notes = vec![];
+    for index in 0..2 * NOTES_PER_STRING {
+        let note = scale_notes[index % scale_notes.len()];
+        if index > 0 {
+            fret += semitones_up(scale_notes[(index - 1) % scale_notes.len()], note);
+        }
+        if index < NOTES_PER_STRING {
+            notes.push((6, fret, note));
+        } else {
+            // The 5th string is tuned five semitones above the 6th string.
+            notes.push((5, fret - 5, note));
+        }
+    }
+    notes
+}
+
+/// Returns the groups of notes of the sequence that can be played in the 6th and 5th strings.
+fn sequence_answer(sequence: &Sequence, scale_notes: &[Note]) -> String {
+    let notes = lowest_string_notes(scale_notes);
+    let group_size = sequence.offsets.iter().max().unwrap() + 1;
+    (0..=notes.len() - group_size)
+        .map(|start| {
+            let group = sequence
+                .offsets
+                .iter()
+                .map(|offset| {
+                    let (string, fret, note) = notes[start + offset];
+                    format!("{} (string {}, fret {})", note.to_string(), string, fret)
+                })
+                .collect::<Vec<String>>()
+                .join(" → ");
+            format!("{}. {}", start + 1, group)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Generates the exercise builders for the lesson with the given scale and key. There's one
+/// exercise for each sequence.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    scale: ScaleType,
+    key: Note,
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = scale.notes(key)?.notes;
+    Ok(SEQUENCES
+        .iter()
+        .map(|sequence| ExerciseBuilder {
+            directory_name: sequence.id.to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the {} {} scale in the sequence of {} across the fretboard.
+                    ", key.to_string(), scale.to_string(), sequence.name.to_lowercase()},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The sequence starts as follows in the 6th and 5th strings:
+
+                        {}
+
+                        Continue the pattern in the rest of the strings and play it back down.
+                    ", sequence_answer(sequence, &scale_notes)},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::{}", lesson_id, sequence.id))
+                    .name(format!(
+                        "{} on the {} {} scale",
+                        sequence.name,
+                        key.to_string(),
+                        scale.to_string()
+                    ))
+                    .clone()
+            }),
+        })
+        .collect())
+}
+
+/// Generates a lesson for each key of the given scale. The lessons follow the circle of fifths. The
+/// note alias is used to obtain the tonic of the scale from each major key.
+fn generate_lesson_builders(
+    scale: ScaleType,
+    scale_id: &str,
+    note_alias: fn(Note) -> Result<Note>,
+) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let mut lessons = vec![];
+    for (key, previous_key) in circle_fifths_keys() {
+        let note = note_alias(key)?;
+        let previous_note = previous_key.map(note_alias).transpose()?;
+        let lesson_id = Ustr::from(&format!(
+            "{}::{}::{}",
+            course_id,
+            scale_id,
+            note.to_string()
+        ));
+        let scale_id = scale_id.to_string();
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_{}_{}", scale_id, note.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, scale, note)?,
+            manifest_closure: Box::new(move |m| {
+                let deps = match previous_note {
+                    None => vec![],
+                    Some(previous_note) => vec![Ustr::from(&format!(
+                        "{}::{}::{}",
+                        course_id,
+                        scale_id,
+                        previous_note.to_string()
+                    ))],
+                };
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Sequences on the {} {} Scale",
+                        note.to_string(),
+                        scale.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} {} scale in 3rds, 4ths, and groups of four notes.",
+                        note.to_string(),
+                        scale.to_string()
+                    )))
+                    .dependencies(deps)
+                    .metadata(Some(BTreeMap::from([
+                        (MusicMetadata::Key.to_string(), vec![note.to_ascii_string()]),
+                        (
+                            MusicMetadata::ScaleType.to_string(),
+                            vec![scale.to_string().to_lowercase()],
+                        ),
+                    ])))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders =
+        generate_lesson_builders(ScaleType::Major, "major", note_aliases::no_alias)?;
+    lesson_builders.extend(generate_lesson_builders(
+        ScaleType::Minor,
+        "minor",
+        note_aliases::relative_minor_alias,
+    )?);
+
+    Ok(CourseBuilder {
+        directory_name: "scale_sequences".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Scale Sequences".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *minor_scale::COURSE_ID],
+            description: Some(
+                "Play the major and minor scales in all keys using common melodic sequences."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["scales"])
+    .with_standard_instruction_asset(indoc! {"
+        Scale sequences break the scale into small repeating groups of notes. Each group
+        starts one note higher in the scale than the one before it.
+
+        Sequences are a common source of melodic material. Once the pattern feels
+        comfortable, try to use it while improvising over a backing track.
+    "}))
+}
@@ -0,0 +1,73 @@
+//! Courses to explore the two modes of the symmetric diminished scale, which alternates half and
+//! whole steps. The whole-half mode is introduced first, and the half-whole mode depends on it.
+
+use anyhow::Result;
+use indoc::indoc;
+use lazy_static::lazy_static;
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+use crate::{
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, Genre},
+    music::{CustomScale, GuitarScale},
+};
+
+lazy_static! {
+    pub static ref WHOLE_HALF_COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::diminished_whole_half");
+    pub static ref HALF_WHOLE_COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::fretboard_exploration::diminished_half_whole");
+}
+
+/// Returns the course builder for the whole-half diminished scale.
+fn whole_half_course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*WHOLE_HALF_COURSE_ID)
+        .dependencies(vec![*major_scale::COURSE_ID])
+        .directory_name("fretboard_diminished_whole_half_scale")
+        .scale(GuitarScale::Custom(CustomScale::DiminishedWholeHalf))
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Jazz])
+        .instructions(indoc! {"
+            The whole-half diminished scale has eight notes, which alternate between whole
+            and half steps starting with a whole step. It contains the four notes of the
+            diminished 7th chord built on its root, and the other four notes fall a whole
+            step above each of them. Play it over diminished 7th chords.
+
+            The pattern repeats every three frets, so any fingering can be moved three frets
+            up or down to play the same notes.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
+
+/// Returns the course builder for the half-whole diminished scale.
+fn half_whole_course_builder() -> Result<CourseBuilder> {
+    let scale_course = FretboardExplorationCourse::builder()
+        .course_id(*HALF_WHOLE_COURSE_ID)
+        .dependencies(vec![*WHOLE_HALF_COURSE_ID])
+        .directory_name("fretboard_diminished_half_whole_scale")
+        .scale(GuitarScale::Custom(CustomScale::DiminishedHalfWhole))
+        .difficulty(Difficulty::Advanced)
+        .genres(vec![Genre::Jazz])
+        .instructions(indoc! {"
+            The half-whole diminished scale has eight notes, which alternate between half and
+            whole steps starting with a half step. It contains the root, 3rd, 5th, and ♭7th of
+            the dominant 7th chord built on its root, along with the ♭9th, ♯9th, and ♯11th.
+            Play it over dominant 7th chords to add tension before they resolve.
+
+            It contains the same notes as the whole-half diminished scale which starts a half
+            step above its root.
+        "})
+        .build()?;
+    scale_course.course_builder()
+}
+
+/// Returns the course builders for both modes of the scale.
+pub fn course_builders() -> Result<Vec<CourseBuilder>> {
+    Ok(vec![
+        whole_half_course_builder()?,
+        half_whole_course_builder()?,
+    ])
+}
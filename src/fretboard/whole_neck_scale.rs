@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::positions,
+    music::{semitones, semitones_up},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::fretboard::whole_neck");
+}
+
+/// The highest fret shown in the diagram and reached by the scale.
+const LAST_FRET: u8 = 12;
+
+/// Returns the ID of the lesson for the given key.
+fn lesson_id(key: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()))
+}
+
+/// Returns an ASCII diagram of the neck in standard tuning from the open strings to the last fret,
+/// with the 1st string at the top. The root of the scale is marked with `R` and every other note of
+/// the scale with `o`.
+fn neck_diagram(key: Note, scale_notes: &[Note]) -> String {
+    let header = (0..=LAST_FRET)
+        .map(|fret| format!("{:^3}", fret))
+        .collect::<Vec<String>>()
+        .join(" ")
+        .trim_end()
+        .to_string();
+    let rows = tunings::STANDARD
+        .iter()
+        .rev()
+        .map(|guitar_string| {
+            let frets = (0..=LAST_FRET)
+                .map(|fret| {
+                    let pitch = (semitones(guitar_string.open_note) + fret) % 12;
+                    if pitch == semitones(key) {
+                        "-R-"
+                    } else if scale_notes.iter().any(|note| semitones(*note) == pitch) {
+                        "-o-"
+                    } else {
+                        "---"
+                    }
+                })
+                .collect::<Vec<&str>>()
+                .join("|");
+            format!(
+                "{} {}|",
+                guitar_string.open_note.to_ascii_string().to_lowercase(),
+                frets
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        ```
+          {}
+        {}
+        ```
+    ", header, rows}
+}
+
+/// Generates the exercise builder for the lesson of the given key, which contains a single
+/// exercise.
+fn generate_exercise_builder(lesson_id: Ustr, key: Note) -> Result<ExerciseBuilder> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let notes = scale_notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let lowest_root = semitones_up(tunings::STANDARD[0].open_note, key);
+
+    Ok(ExerciseBuilder {
+        directory_name: "whole_neck".to_string(),
+        asset_builders: vec![
+            AssetBuilder {
+                file_name: "front.md".to_string(),
+                contents: formatdoc! {"
+                    Play the {} major scale ascending from the open strings up to the 12th fret
+                    and back down without stopping. Use any fingering and move across the
+                    positions freely.
+                ", key.to_string()},
+            },
+            AssetBuilder {
+                file_name: "back.md".to_string(),
+                contents: formatdoc! {"
+                    The notes of the {} major scale are: {}. The lowest root is found in fret {}
+                    of the 6th string.
+
+                    The diagram shows every note of the scale in the neck, with the root marked
+                    as R and the other notes as o.
+
+                    {}
+                ", key.to_string(), notes, lowest_root, neck_diagram(key, &scale_notes)},
+            },
+        ],
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(format!("{}::whole_neck", lesson_id))
+                .name(format!(
+                    "Play the {} major scale across the whole neck",
+                    key.to_string()
+                ))
+                .clone()
+        }),
+    })
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        let dependencies = key
+            .previous_key_in_circle()
+            .map(lesson_id)
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = lesson_id(key);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: vec![generate_exercise_builder(lesson_id, key)?],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "The {} Major Scale Across the Whole Neck",
+                        key.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} major scale up and down the neck without stopping.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![key.to_ascii_string()],
+                    )])))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "fretboard_whole_neck".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "The Major Scale Across the Whole Neck".to_string(),
+            dependencies: vec![*positions::COURSE_ID],
+            description: Some(
+                "Play the major scale continuously up and down the full neck in all keys."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["scales"])
+    .with_scale_metadata(ScaleType::Major)
+    .with_standard_instruction_asset(indoc! {"
+        Instead of practicing each position on its own, this course asks you to play
+        the major scale up and down the whole neck in a single pass. Any fingering is
+        allowed, so shift positions, slide, or move along a single string as needed.
+
+        The goal is to keep the scale flowing without stopping. Once that's easy, try
+        to take a different path across the neck each time.
+    "}))
+}
@@ -0,0 +1,109 @@
+//! The graph formed by the dependencies between the courses in the library.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+use trane::course_builder::CourseBuilder;
+use ustr::Ustr;
+
+/// The directed graph of the courses in the library, with an edge from each course to each of its
+/// dependencies.
+pub struct CourseGraph {
+    /// The dependencies of each course, indexed by the ID of the course.
+    edges: BTreeMap<Ustr, Vec<Ustr>>,
+
+    /// The IDs of the lessons in all the courses. Courses are allowed to depend on a single lesson
+    /// of another course.
+    lesson_ids: BTreeSet<Ustr>,
+}
+
+impl CourseGraph {
+    /// Builds the graph from the dependencies declared in the manifests of the given courses.
+    pub fn new(course_builders: &[CourseBuilder]) -> Result<Self> {
+        let mut lesson_ids = BTreeSet::new();
+        for course_builder in course_builders {
+            for lesson_builder in &course_builder.lesson_builders {
+                let lesson_manifest = (lesson_builder.manifest_closure)(
+                    course_builder.lesson_manifest_template.clone(),
+                )
+                .build()?;
+                lesson_ids.insert(lesson_manifest.id);
+            }
+        }
+
+        Ok(CourseGraph {
+            edges: course_builders
+                .iter()
+                .map(|course_builder| {
+                    (
+                        course_builder.course_manifest.id,
+                        course_builder.course_manifest.dependencies.clone(),
+                    )
+                })
+                .collect(),
+            lesson_ids,
+        })
+    }
+
+    /// Returns whether the given ID belongs to a course or lesson in the graph.
+    fn contains(&self, id: &Ustr) -> bool {
+        self.edges.contains_key(id) || self.lesson_ids.contains(id)
+    }
+
+    /// Verifies that every dependency refers to a course or lesson in the graph, so that a typo in
+    /// a course ID doesn't go unnoticed until the library is opened. The error lists all the
+    /// missing dependencies along with the courses which declare them.
+    pub fn validate(&self) -> Result<()> {
+        let missing = self
+            .edges
+            .iter()
+            .flat_map(|(course_id, dependencies)| {
+                dependencies
+                    .iter()
+                    .filter(|dependency| !self.contains(dependency))
+                    .map(move |dependency| format!("{} -> {}", course_id, dependency))
+            })
+            .collect::<Vec<String>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "found dependencies on unknown courses: {}",
+                missing.join(", ")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use ustr::Ustr;
+
+    use crate::graph::CourseGraph;
+
+    #[test]
+    fn missing_dependencies() {
+        let graph = CourseGraph {
+            edges: BTreeMap::from([
+                (Ustr::from("a"), vec![]),
+                (Ustr::from("b"), vec![Ustr::from("a")]),
+                (Ustr::from("c"), vec![Ustr::from("a::lesson")]),
+            ]),
+            lesson_ids: BTreeSet::from([Ustr::from("a::lesson")]),
+        };
+        assert!(graph.validate().is_ok());
+
+        let graph = CourseGraph {
+            edges: BTreeMap::from([
+                (Ustr::from("a"), vec![]),
+                (Ustr::from("b"), vec![Ustr::from("a"), Ustr::from("c")]),
+            ]),
+            lesson_ids: BTreeSet::new(),
+        };
+        let result = graph.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("b -> c"));
+    }
+}
@@ -0,0 +1,81 @@
+//! Support for building courses in memory, so that the generated manifests can be inspected
+//! without writing the course to disk.
+
+use anyhow::Result;
+use trane::{
+    course_builder::CourseBuilder,
+    data::{CourseManifest, ExerciseManifest, LessonManifest},
+};
+
+/// The manifests of a course which has been built in memory.
+pub struct InMemoryCourse {
+    /// The manifest of the course.
+    pub course_manifest: CourseManifest,
+
+    /// The manifest of each lesson in the course, along with the manifests of its exercises.
+    pub lessons: Vec<(LessonManifest, Vec<ExerciseManifest>)>,
+}
+
+impl InMemoryCourse {
+    /// Returns the number of lessons in the course.
+    #[cfg(test)]
+    pub fn lesson_count(&self) -> usize {
+        self.lessons.len()
+    }
+
+    /// Returns the number of exercises in all the lessons of the course.
+    #[cfg(test)]
+    pub fn exercise_count(&self) -> usize {
+        self.lessons
+            .iter()
+            .map(|(_, exercise_manifests)| exercise_manifests.len())
+            .sum()
+    }
+}
+
+/// Runs all the manifest closures of the course builder and collects the resulting manifests in
+/// the same order in which the course builder would write them.
+pub fn build_course_in_memory(builder: &CourseBuilder) -> Result<InMemoryCourse> {
+    let lessons = builder
+        .lesson_builders
+        .iter()
+        .map(|lesson_builder| {
+            let lesson_manifest =
+                (lesson_builder.manifest_closure)(builder.lesson_manifest_template.clone())
+                    .build()?;
+            let exercise_manifests = lesson_builder
+                .exercise_builders
+                .iter()
+                .map(|exercise_builder| {
+                    Ok((exercise_builder.manifest_closure)(
+                        lesson_builder.exercise_manifest_template.clone(),
+                    )
+                    .build()?)
+                })
+                .collect::<Result<Vec<ExerciseManifest>>>()?;
+            Ok((lesson_manifest, exercise_manifests))
+        })
+        .collect::<Result<Vec<(LessonManifest, Vec<ExerciseManifest>)>>>()?;
+    Ok(InMemoryCourse {
+        course_manifest: builder.course_manifest.clone(),
+        lessons,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::warmup};
+
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&warmup::course_builder())?;
+        assert_eq!(course.course_manifest.id, *warmup::COURSE_ID);
+        assert_eq!(course.lesson_count(), 3);
+        assert_eq!(course.exercise_count(), 9);
+        assert!(course
+            .lessons
+            .iter()
+            .all(|(lesson_manifest, _)| lesson_manifest.course_id == *warmup::COURSE_ID));
+        Ok(())
+    }
+}
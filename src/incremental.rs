@@ -0,0 +1,138 @@
+//! Support for rebuilding only the courses whose generated files have changed.
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    fs::{read_dir, read_to_string, remove_dir_all},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use trane::course_builder::{AssetBuilder, CourseBuilder};
+
+/// Adds the files written by the given asset builders to the map of files.
+fn add_assets(files: &mut BTreeMap<PathBuf, String>, directory: &Path, assets: &[AssetBuilder]) {
+    for asset_builder in assets {
+        files.insert(
+            directory.join(&asset_builder.file_name),
+            asset_builder.contents.clone(),
+        );
+    }
+}
+
+/// Returns the contents of all the files which the course builder writes, indexed by their path
+/// relative to the directory of the course. The manifests are serialized in the same way as the
+/// course builder does when it writes them to disk.
+fn expected_files(course_builder: &CourseBuilder) -> Result<BTreeMap<PathBuf, String>> {
+    let mut files = BTreeMap::new();
+    files.insert(
+        PathBuf::from("course_manifest.json"),
+        serde_json::to_string_pretty(&course_builder.course_manifest)? + "\n",
+    );
+    add_assets(&mut files, Path::new(""), &course_builder.asset_builders);
+
+    for lesson_builder in &course_builder.lesson_builders {
+        let lesson_directory = PathBuf::from(&lesson_builder.directory_name);
+        let lesson_manifest =
+            (lesson_builder.manifest_closure)(course_builder.lesson_manifest_template.clone())
+                .build()?;
+        files.insert(
+            lesson_directory.join("lesson_manifest.json"),
+            serde_json::to_string_pretty(&lesson_manifest)? + "\n",
+        );
+        add_assets(
+            &mut files,
+            &lesson_directory,
+            &lesson_builder.asset_builders,
+        );
+
+        for exercise_builder in &lesson_builder.exercise_builders {
+            let exercise_directory = lesson_directory.join(&exercise_builder.directory_name);
+            let exercise_manifest = (exercise_builder.manifest_closure)(
+                lesson_builder.exercise_manifest_template.clone(),
+            )
+            .build()?;
+            files.insert(
+                exercise_directory.join("exercise_manifest.json"),
+                serde_json::to_string_pretty(&exercise_manifest)? + "\n",
+            );
+            add_assets(
+                &mut files,
+                &exercise_directory,
+                &exercise_builder.asset_builders,
+            );
+        }
+    }
+    Ok(files)
+}
+
+/// Adds the contents of all the files under the given directory to the map of files, indexed by
+/// their path relative to the root directory.
+fn add_existing_files(
+    files: &mut BTreeMap<PathBuf, String>,
+    root: &Path,
+    directory: &Path,
+) -> Result<()> {
+    for entry in read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            add_existing_files(files, root, &path)?;
+        } else {
+            files.insert(
+                path.strip_prefix(root)?.to_path_buf(),
+                read_to_string(&path)?,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Returns a hash of the paths and contents of the files. The map is sorted by path, so the hash
+/// does not depend on the order in which the files were found.
+fn files_hash(files: &BTreeMap<PathBuf, String>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    files.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Writes the course to the library unless the files already in the directory of the course match
+/// the files that would be written. Otherwise, the directory is removed and the course is built
+/// again. Returns whether the course was written.
+pub fn build_if_changed(course_builder: &CourseBuilder, library_root: &Path) -> Result<bool> {
+    let course_directory = library_root.join(&course_builder.directory_name);
+    if course_directory.is_dir() {
+        let mut existing_files = BTreeMap::new();
+        add_existing_files(&mut existing_files, &course_directory, &course_directory)?;
+        if files_hash(&existing_files) == files_hash(&expected_files(course_builder)?) {
+            return Ok(false);
+        }
+        remove_dir_all(&course_directory)?;
+    }
+    course_builder.build(library_root)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::write;
+
+    use crate::{fretboard::chromatic_scale, incremental::build_if_changed};
+
+    #[test]
+    fn skip_unchanged_courses() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = temp_dir.path();
+        let course_builder = chromatic_scale::course_builder();
+        assert!(build_if_changed(&course_builder, library_root)?);
+        assert!(!build_if_changed(&course_builder, library_root)?);
+
+        // Modifying any of the files causes the course to be written again.
+        let instructions_path = library_root
+            .join(&course_builder.directory_name)
+            .join("course_instructions.md");
+        write(&instructions_path, "Modified instructions.")?;
+        assert!(build_if_changed(&course_builder, library_root)?);
+        assert!(!build_if_changed(&course_builder, library_root)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! Snippets of text shared by the instructions of multiple courses, so that common topics are
+//! explained in the same way everywhere.
+
+use indoc::indoc;
+
+/// Explains why the lessons of a course follow the circle of fifths.
+pub fn circle_of_fifths_note() -> String {
+    indoc! {"
+        The lessons follow the circle of fifths, starting with C and moving to the keys with
+        one more sharp or flat each time. Since each key only changes one note with respect to
+        the key before it, most of what was learned in the previous key carries over.
+    "}
+    .to_string()
+}
+
+/// Explains how to rate the exercises which are graded by the student.
+pub fn self_assessment_guide() -> String {
+    indoc! {"
+        These exercises are graded by yourself. Give a low score when you needed to stop,
+        guess, or look at the answer, and a high score only when you could answer or play
+        the exercise right away and without mistakes.
+    "}
+    .to_string()
+}
+
+/// Suggests playing the exercises over a backing track.
+pub fn backing_track_suggestion() -> String {
+    indoc! {"
+        You can use a vamp or backing track, although they are not provided
+        here.
+    "}
+    .to_string()
+}
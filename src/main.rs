@@ -1,46 +1,279 @@
 //! Code to generate all the music courses.
+mod builder_ext;
+mod chords;
+mod constants;
 mod fretboard;
+mod graph;
+mod in_memory;
+mod incremental;
+mod instructions;
+mod metadata;
+mod music;
+mod practice_log;
+mod progressions;
+mod technique;
+mod theory;
+mod tunings;
+mod validation;
+mod vocabulary;
 
-use std::path::Path;
+use std::{
+    fs::{read_dir, remove_dir_all},
+    path::Path,
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use ustr::Ustr;
 
-static AUTHORS: &str = "The Trane Project";
+use crate::builder_ext::GuitarCourseBuilderExt;
 
-fn build_courses(library_root: &Path) -> Result<()> {
-    let course_builders = vec![
+/// The number of lessons and exercises in a course which has been written to disk.
+#[derive(Debug, PartialEq, Eq)]
+struct CourseStats {
+    lesson_count: usize,
+    exercise_count: usize,
+}
+
+impl CourseStats {
+    /// Counts the lesson and exercise manifests found in the directory of the course.
+    fn from_directory(course_directory: &Path) -> Result<Self> {
+        let mut stats = CourseStats {
+            lesson_count: 0,
+            exercise_count: 0,
+        };
+        for lesson_entry in read_dir(course_directory)? {
+            let lesson_directory = lesson_entry?.path();
+            if !lesson_directory.join("lesson_manifest.json").is_file() {
+                continue;
+            }
+            stats.lesson_count += 1;
+
+            for exercise_entry in read_dir(&lesson_directory)? {
+                let exercise_directory = exercise_entry?.path();
+                if exercise_directory.join("exercise_manifest.json").is_file() {
+                    stats.exercise_count += 1;
+                }
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Builds the courses in the given directory. If `course_ids` is not empty, only the courses with
+/// those IDs are built, and their existing directories are replaced. If `incremental` is true,
+/// courses whose files are already up to date are not written again. If `with_practice_log` is
+/// true, a practice log is written along with each lesson.
+fn build_courses(
+    library_root: &Path,
+    course_ids: &[Ustr],
+    incremental: bool,
+    with_practice_log: bool,
+) -> Result<()> {
+    let mut course_builders = vec![
+        chords::chord_melody::course_builder()?,
+        chords::extended_voicings::course_builder(),
+        chords::slash_chords::course_builder()?,
+        chords::suspended_chords::course_builder()?,
         fretboard::basic_guitar_fretboard::course_builder(),
         fretboard::advanced_guitar_fretboard::course_builder(),
+        fretboard::chromatic_scale::course_builder(),
         fretboard::major_scale::course_builder()?,
         fretboard::minor_scale::course_builder()?,
+        fretboard::major_pentatonic_scale::course_builder()?,
+        fretboard::minor_pentatonic_scale::course_builder()?,
+        fretboard::double_harmonic_scale::course_builder()?,
+        fretboard::half_step_down_major::course_builder()?,
+        fretboard::full_step_down_major::course_builder()?,
+        fretboard::dadgad::major_scale::course_builder()?,
+        fretboard::open_e::major_scale::course_builder()?,
+        fretboard::open_g::major_scale::course_builder()?,
+        fretboard::note_position_quiz::course_builder(),
+        fretboard::geography_quiz::course_builder(),
+        fretboard::positions::course_builder()?,
+        fretboard::position_shifts::course_builder()?,
+        fretboard::whole_neck_scale::course_builder()?,
+        fretboard::scale_sequences::course_builder()?,
+        fretboard::cross_string_sequences::course_builder()?,
+        progressions::modal_progressions::course_builder()?,
+        progressions::twelve_bar_blues::course_builder()?,
+        technique::alternate_picking::course_builder()?,
+        technique::artificial_harmonics::course_builder(),
+        technique::ear_training::course_builder()?,
+        technique::economy_picking::course_builder()?,
+        technique::expressive::course_builder(),
+        technique::fingerpicking::course_builder(),
+        technique::legato::course_builder()?,
+        technique::natural_harmonics::course_builder(),
+        technique::rhythmic_scale_practice::course_builder()?,
+        technique::sight_reading::course_builder()?,
+        technique::string_skipping::course_builder()?,
+        technique::sweep_picking::course_builder()?,
+        technique::tapping::course_builder()?,
+        technique::warmup::course_builder(),
+        theory::basic_theory::course_builder()?,
+        theory::capo_transposition::course_builder(),
+        theory::chord_tones_in_scale::course_builder()?,
+        theory::fretboard_intervals::course_builder(),
+        theory::parallel_scales::course_builder()?,
+        theory::pentatonic_blues_transition::course_builder()?,
+        theory::relative_keys::course_builder()?,
+        theory::scale_degrees::course_builder()?,
+        theory::voice_leading::course_builder()?,
+        vocabulary::pentatonic_licks::course_builder()?,
     ];
+    course_builders.extend(fretboard::modes::course_builders()?);
+    course_builders.push(fretboard::mixolydian_blues::course_builder()?);
+    course_builders.extend(fretboard::symmetric_diminished::course_builders()?);
+    graph::CourseGraph::new(&course_builders)?.validate()?;
+
+    if let Some(unknown_id) = course_ids.iter().find(|course_id| {
+        !course_builders
+            .iter()
+            .any(|course_builder| course_builder.course_manifest.id == **course_id)
+    }) {
+        return Err(anyhow!("no course with ID {} was found", unknown_id));
+    }
+    if !course_ids.is_empty() {
+        course_builders
+            .retain(|course_builder| course_ids.contains(&course_builder.course_manifest.id));
+    }
 
     for course_builder in course_builders {
-        course_builder.build(library_root)?;
-        println!("Built {} course", course_builder.course_manifest.name);
+        validation::validate_course_builder(&course_builder)?;
+        let mut course_builder = course_builder.with_version_metadata();
+        if with_practice_log {
+            practice_log::add_practice_logs(&mut course_builder, practice_log::DEFAULT_TEMPLATE)?;
+        }
+        if incremental {
+            if !incremental::build_if_changed(&course_builder, library_root)? {
+                println!(
+                    "Skipped {} course (unchanged)",
+                    course_builder.course_manifest.name
+                );
+                continue;
+            }
+        } else {
+            let course_directory = library_root.join(&course_builder.directory_name);
+            if !course_ids.is_empty() && course_directory.is_dir() {
+                remove_dir_all(&course_directory)?;
+            }
+            course_builder.build(library_root)?;
+        }
+        let stats =
+            CourseStats::from_directory(&library_root.join(&course_builder.directory_name))?;
+        println!(
+            "Built {} course ({} lessons, {} exercises)",
+            course_builder.course_manifest.name, stats.lesson_count, stats.exercise_count
+        );
     }
     Ok(())
 }
 
 fn main() -> Result<()> {
     let curr_dir = std::env::current_dir()?;
-    build_courses(curr_dir.as_path())
+    let incremental = std::env::args().any(|arg| arg == "--incremental");
+    let with_practice_log = std::env::args().any(|arg| arg == "--with-practice-log");
+    // Any argument which is not a flag is the ID of a course to build.
+    let course_ids = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|arg| Ustr::from(&arg))
+        .collect::<Vec<Ustr>>();
+    build_courses(
+        curr_dir.as_path(),
+        &course_ids,
+        incremental,
+        with_practice_log,
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use trane::scheduler::ExerciseScheduler;
+    use std::{
+        collections::HashSet,
+        fs::read_dir,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use trane::{course_library::CourseLibrary, data::MasteryScore, scheduler::ExerciseScheduler};
+    use ustr::Ustr;
+
+    use crate::{build_courses, fretboard, CourseStats};
 
-    use crate::build_courses;
+    /// The maximum number of batches requested from the scheduler before giving up on reaching all
+    /// the exercises.
+    const MAX_ITERATIONS: usize = 1000;
 
     #[test]
     fn open_library() -> anyhow::Result<()> {
         let temp_dir = tempfile::TempDir::new()?;
         let library_root = &temp_dir.path().to_path_buf();
-        build_courses(library_root)?;
+        build_courses(library_root, &[], false, false)?;
         let trane = trane::Trane::new(library_root, library_root)?;
         let batch = trane.get_exercise_batch(None)?;
         assert!(!batch.is_empty());
+
+        // The major scale course has one lesson per key in the circle of fifths, including the
+        // enharmonic keys, and one exercise per string in standard tuning. Each key also has a
+        // bridge lesson to its relative minor with a single exercise.
+        let stats = CourseStats::from_directory(&library_root.join("fretboard_major_scale"))?;
+        assert_eq!(
+            stats,
+            CourseStats {
+                lesson_count: 30,
+                exercise_count: 105,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn build_selected_courses() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = &temp_dir.path().to_path_buf();
+        let course_ids = [*fretboard::major_scale::COURSE_ID];
+        build_courses(library_root, &course_ids, false, false)?;
+        // Building the course again replaces its directory.
+        build_courses(library_root, &course_ids, false, false)?;
+        assert_eq!(read_dir(library_root)?.count(), 1);
+        assert!(library_root.join("fretboard_major_scale").is_dir());
+
+        assert!(build_courses(library_root, &[Ustr::from("unknown")], false, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn all_exercises_reachable() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let library_root = &temp_dir.path().to_path_buf();
+        build_courses(library_root, &[], false, false)?;
+        let trane = trane::Trane::new(library_root, library_root)?;
+
+        // Master every exercise returned by the scheduler until all of them have been seen. The
+        // scheduler keeps returning mastered exercises, so the loop stops once there's nothing
+        // left to find.
+        let mut unseen: HashSet<Ustr> = trane.get_all_exercise_ids()?.into_iter().collect();
+        for _ in 0..MAX_ITERATIONS {
+            let batch = trane.get_exercise_batch(None)?;
+            if batch.is_empty() || unseen.is_empty() {
+                break;
+            }
+            let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+            for (exercise_id, _) in batch {
+                trane.score_exercise(&exercise_id, MasteryScore::Five, timestamp)?;
+                unseen.remove(&exercise_id);
+            }
+        }
+
+        let mut unseen = unseen.into_iter().collect::<Vec<Ustr>>();
+        unseen.sort();
+        assert!(
+            unseen.is_empty(),
+            "{} exercises were not reached after {} batches: {:?}",
+            unseen.len(),
+            MAX_ITERATIONS,
+            unseen
+        );
         Ok(())
     }
 }
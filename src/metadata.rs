@@ -0,0 +1,189 @@
+//! Metadata specific to the guitar courses which is not provided by Trane.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+/// The metadata key under which the recommended tempo of the exercises is stored.
+pub const RECOMMENDED_BPM_KEY: &str = "guitar::recommended_bpm";
+
+/// The metadata key under which the difficulty of the exercises is stored.
+pub const DIFFICULTY_KEY: &str = "guitar::difficulty";
+
+/// The metadata key under which the URL of the backing track for the exercises is stored.
+pub const BACKING_TRACK_URL_KEY: &str = "guitar::backing_track_url";
+
+/// The metadata key under which the physical techniques practiced in a course are stored. Trane
+/// does not define a technique variant in `MusicMetadata`, so a custom key is used instead.
+pub const TECHNIQUE_KEY: &str = "guitar::technique";
+
+/// The metadata key under which the open notes of the strings practiced in a lesson are stored.
+pub const STRING_KEY: &str = "guitar::string";
+
+/// The metadata key under which the genres of a course are stored.
+pub const GENRE_KEY: &str = "guitar::genre";
+
+/// The metadata key under which the IDs of the courses recommended after finishing a course are
+/// stored. Unlike dependencies, they don't affect the order in which the courses are scheduled.
+pub const RECOMMENDED_NEXT_KEY: &str = "guitar::recommended_next";
+
+/// The metadata key under which the version of trane-guitar which generated a course is stored.
+pub const VERSION_KEY: &str = "guitar::version";
+
+/// The difficulty of a set of exercises.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Beginner => write!(f, "beginner"),
+            Difficulty::Intermediate => write!(f, "intermediate"),
+            Difficulty::Advanced => write!(f, "advanced"),
+            Difficulty::Expert => write!(f, "expert"),
+        }
+    }
+}
+
+/// A musical genre to which the material of a course is relevant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Genre {
+    Blues,
+    Jazz,
+    Rock,
+    Metal,
+    Classical,
+    Country,
+    Folk,
+    Pop,
+    Flamenco,
+}
+
+impl Display for Genre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Genre::Blues => write!(f, "blues"),
+            Genre::Jazz => write!(f, "jazz"),
+            Genre::Rock => write!(f, "rock"),
+            Genre::Metal => write!(f, "metal"),
+            Genre::Classical => write!(f, "classical"),
+            Genre::Country => write!(f, "country"),
+            Genre::Folk => write!(f, "folk"),
+            Genre::Pop => write!(f, "pop"),
+            Genre::Flamenco => write!(f, "flamenco"),
+        }
+    }
+}
+
+/// Adds the given genres to the metadata of a course. Nothing is added if the list is empty.
+pub fn add_genres(metadata: &mut BTreeMap<String, Vec<String>>, genres: &[Genre]) {
+    if !genres.is_empty() {
+        metadata.insert(
+            GENRE_KEY.to_string(),
+            genres.iter().map(|genre| genre.to_string()).collect(),
+        );
+    }
+}
+
+/// Additional metadata about the exercises in a lesson. Exercise manifests do not have a metadata
+/// field, so the values are stored in the metadata of the lesson and apply to all its exercises.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GuitarExerciseMetadata {
+    /// The tempo in beats per minute at which the exercises should be practiced, if any.
+    pub recommended_bpm: Option<u32>,
+
+    /// The difficulty of the exercises, if any.
+    pub difficulty: Option<Difficulty>,
+
+    /// An optional URL to a backing track or backing track generator (e.g., an iReal Pro URI or a
+    /// YouTube link) to use while practicing the exercises.
+    pub backing_track_url: Option<String>,
+}
+
+impl GuitarExerciseMetadata {
+    /// Adds the values which are set to the given metadata map.
+    pub fn add_to(&self, metadata: &mut BTreeMap<String, Vec<String>>) {
+        if let Some(bpm) = self.recommended_bpm {
+            metadata.insert(RECOMMENDED_BPM_KEY.to_string(), vec![bpm.to_string()]);
+        }
+        if let Some(difficulty) = self.difficulty {
+            metadata.insert(DIFFICULTY_KEY.to_string(), vec![difficulty.to_string()]);
+        }
+        if let Some(url) = &self.backing_track_url {
+            metadata.insert(BACKING_TRACK_URL_KEY.to_string(), vec![url.clone()]);
+        }
+    }
+
+    /// Appends the link to the backing track, if any, to the contents of the back of a card.
+    pub fn with_backing_track(&self, back_contents: String) -> String {
+        match &self.backing_track_url {
+            None => back_contents,
+            Some(url) => format!("{}\nBacking track: {}\n", back_contents, url),
+        }
+    }
+
+    /// Returns a new metadata map with the values which are set.
+    pub fn to_metadata(&self) -> BTreeMap<String, Vec<String>> {
+        let mut metadata = BTreeMap::new();
+        self.add_to(&mut metadata);
+        metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::metadata::{
+        Difficulty, GuitarExerciseMetadata, BACKING_TRACK_URL_KEY, DIFFICULTY_KEY,
+        RECOMMENDED_BPM_KEY,
+    };
+
+    #[test]
+    fn add_metadata() {
+        let mut metadata = BTreeMap::new();
+        GuitarExerciseMetadata::default().add_to(&mut metadata);
+        assert!(metadata.is_empty());
+
+        GuitarExerciseMetadata {
+            recommended_bpm: Some(80),
+            difficulty: Some(Difficulty::Intermediate),
+            backing_track_url: None,
+        }
+        .add_to(&mut metadata);
+        assert_eq!(
+            metadata.get(RECOMMENDED_BPM_KEY),
+            Some(&vec!["80".to_string()])
+        );
+        assert_eq!(
+            metadata.get(DIFFICULTY_KEY),
+            Some(&vec!["intermediate".to_string()])
+        );
+        assert!(!metadata.contains_key(BACKING_TRACK_URL_KEY));
+    }
+
+    #[test]
+    fn backing_track() {
+        let exercise_metadata = GuitarExerciseMetadata::default();
+        assert_eq!(
+            exercise_metadata.with_backing_track("Back".to_string()),
+            "Back"
+        );
+
+        let exercise_metadata = GuitarExerciseMetadata {
+            backing_track_url: Some("https://example.com/track".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            exercise_metadata.with_backing_track("Back\n".to_string()),
+            "Back\n\nBacking track: https://example.com/track\n"
+        );
+        assert_eq!(
+            exercise_metadata.to_metadata().get(BACKING_TRACK_URL_KEY),
+            Some(&vec!["https://example.com/track".to_string()])
+        );
+    }
+}
@@ -0,0 +1,688 @@
+//! Utilities to work with notes, chords, and scales which are not provided by Trane.
+
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use trane::data::music::{
+    intervals::Interval,
+    notes::{Accidental, NaturalNote, Note},
+    scales::ScaleType,
+};
+
+/// The quality of a triad, determined by the intervals between its notes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriadQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+}
+
+impl Display for TriadQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriadQuality::Major => write!(f, "Major"),
+            TriadQuality::Minor => write!(f, "Minor"),
+            TriadQuality::Diminished => write!(f, "Diminished"),
+            TriadQuality::Augmented => write!(f, "Augmented"),
+        }
+    }
+}
+
+/// A triad built from one of the degrees of a scale.
+#[derive(Clone, Debug)]
+pub struct Triad {
+    /// The degree of the scale on which the triad is built, starting at 1.
+    pub degree: usize,
+
+    /// The root, third, and fifth of the triad, in that order.
+    pub notes: Vec<Note>,
+
+    /// The quality of the triad.
+    pub quality: TriadQuality,
+}
+
+impl Triad {
+    /// Returns the root of the triad.
+    pub fn root(&self) -> Note {
+        self.notes[0]
+    }
+
+    /// Returns the name of the chord (e.g., "D Minor").
+    pub fn name(&self) -> String {
+        format!("{} {}", self.root().to_string(), self.quality)
+    }
+
+    /// Returns the Roman numeral of the triad in the key (e.g., "ii" for the second degree of a
+    /// major key).
+    pub fn roman_numeral(&self) -> String {
+        let numeral = ["I", "II", "III", "IV", "V", "VI", "VII"][(self.degree - 1) % 7];
+        match self.quality {
+            TriadQuality::Major => numeral.to_string(),
+            TriadQuality::Minor => numeral.to_lowercase(),
+            TriadQuality::Diminished => format!("{}°", numeral.to_lowercase()),
+            TriadQuality::Augmented => format!("{}+", numeral),
+        }
+    }
+}
+
+/// Returns the pitch class of the note as the number of semitones above C.
+pub fn semitones(note: Note) -> u8 {
+    let natural = match note.0 {
+        NaturalNote::C => 0,
+        NaturalNote::D => 2,
+        NaturalNote::E => 4,
+        NaturalNote::F => 5,
+        NaturalNote::G => 7,
+        NaturalNote::A => 9,
+        NaturalNote::B => 11,
+    };
+    match note.1 {
+        Accidental::Natural => natural,
+        Accidental::Flat => (natural + 11) % 12,
+        Accidental::Sharp => (natural + 1) % 12,
+    }
+}
+
+/// Returns the note with the given pitch class. Notes outside the C major scale are spelled with
+/// sharps.
+pub fn note_from_semitones(pitch: u8) -> Note {
+    match pitch % 12 {
+        0 => Note::C,
+        1 => Note::C_SHARP,
+        2 => Note::D,
+        3 => Note::D_SHARP,
+        4 => Note::E,
+        5 => Note::F,
+        6 => Note::F_SHARP,
+        7 => Note::G,
+        8 => Note::G_SHARP,
+        9 => Note::A,
+        10 => Note::A_SHARP,
+        _ => Note::B,
+    }
+}
+
+/// Returns the note with the given pitch class. Notes outside the C major scale are spelled with
+/// flats.
+pub fn note_from_semitones_flat(pitch: u8) -> Note {
+    match pitch % 12 {
+        1 => Note::D_FLAT,
+        3 => Note::E_FLAT,
+        6 => Note::G_FLAT,
+        8 => Note::A_FLAT,
+        10 => Note::B_FLAT,
+        pitch => note_from_semitones(pitch),
+    }
+}
+
+/// Returns the name of the note with the given pitch class, including both spellings if the note
+/// has an accidental.
+pub fn chromatic_note_name(pitch: u8) -> String {
+    let sharp = note_from_semitones(pitch);
+    let flat = note_from_semitones_flat(pitch);
+    if sharp == flat {
+        sharp.to_string()
+    } else {
+        format!("{}/{}", sharp.to_string(), flat.to_string())
+    }
+}
+
+/// Returns the conventional spelling of the note as the root of a key, which is the spelling whose
+/// key signature has the fewest accidentals. Notes such as E♯ or C♭ are replaced by the natural note
+/// with the same pitch, and the rest of the notes are spelled with flats (e.g., A♯ becomes B♭),
+/// except for F♯, whose key signature has as many sharps as G♭ has flats.
+pub fn respell_enharmonic(note: Note) -> Note {
+    match semitones(note) {
+        6 => Note::F_SHARP,
+        pitch => note_from_semitones_flat(pitch),
+    }
+}
+
+/// Extra methods for notes which are not provided by Trane.
+pub trait NoteExt {
+    /// Returns the name of the note using the given accidental style. Notes spelled with the other
+    /// accidental are replaced by their enharmonic equivalent (e.g., F♯ is shown as G♭ when
+    /// preferring flats). Notes without accidentals are never changed.
+    fn to_string_with_accidental(&self, prefer_flats: bool) -> String;
+}
+
+impl NoteExt for Note {
+    fn to_string_with_accidental(&self, prefer_flats: bool) -> String {
+        match (self.1, prefer_flats) {
+            (Accidental::Sharp, true) => note_from_semitones_flat(semitones(*self)).to_string(),
+            (Accidental::Flat, false) => note_from_semitones(semitones(*self)).to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// Returns the number of semitones needed to go up from one note to the other, between 0 and 11.
+pub fn semitones_up(from: Note, to: Note) -> u8 {
+    (semitones(to) + 12 - semitones(from)) % 12
+}
+
+/// Returns the number of semitones in the given interval.
+pub fn interval_semitones(interval: Interval) -> u8 {
+    match interval {
+        Interval::Unison => 0,
+        Interval::MinorSecond => 1,
+        Interval::MajorSecond => 2,
+        Interval::MinorThird => 3,
+        Interval::MajorThird => 4,
+        Interval::PerfectFourth => 5,
+        Interval::Tritone => 6,
+        Interval::PerfectFifth => 7,
+        Interval::MinorSixth => 8,
+        Interval::MajorSixth => 9,
+        Interval::MinorSeventh => 10,
+        Interval::MajorSeventh => 11,
+        Interval::Octave => 12,
+    }
+}
+
+/// Returns the shortest motion in semitones from one note to the other. Positive values indicate
+/// upward motion and negative values indicate downward motion. A tritone is always returned as an
+/// upward motion.
+pub fn shortest_motion(from: Note, to: Note) -> i8 {
+    let up = semitones_up(from, to) as i8;
+    if up > 6 {
+        up - 12
+    } else {
+        up
+    }
+}
+
+/// Returns the frets at which the target note is found in a string with the given open note. Only
+/// the lowest two octaves of the string (frets 0 to 23) are considered.
+pub fn note_to_fret(string_open: Note, target: Note) -> Vec<u8> {
+    let fret = semitones_up(string_open, target);
+    vec![fret, fret + 12]
+}
+
+/// Returns all the keys in the circle of fifths along with the key that precedes them, if any.
+/// Following this order, each key only adds one sharp or flat to the key before it, which is the
+/// same order followed by the lessons generated by `CircleFifthsCourse`.
+pub fn circle_fifths_keys() -> Vec<(Note, Option<Note>)> {
+    Note::all_keys(true)
+        .into_iter()
+        .map(|key| (key, key.previous_key_in_circle()))
+        .collect()
+}
+
+/// Returns the triad built on the given degree (starting at 1) of the major scale of the given key.
+pub fn diatonic_triad(key: Note, degree: usize) -> Result<Triad> {
+    if !(1..=7).contains(&degree) {
+        return Err(anyhow!("invalid scale degree {}", degree));
+    }
+    let scale = ScaleType::Major.notes(key)?.notes;
+    let notes = vec![
+        scale[degree - 1],
+        scale[(degree + 1) % 7],
+        scale[(degree + 3) % 7],
+    ];
+    let quality = match (
+        semitones_up(notes[0], notes[1]),
+        semitones_up(notes[1], notes[2]),
+    ) {
+        (4, 3) => TriadQuality::Major,
+        (3, 4) => TriadQuality::Minor,
+        (3, 3) => TriadQuality::Diminished,
+        (4, 4) => TriadQuality::Augmented,
+        _ => return Err(anyhow!("cannot determine the quality of the triad")),
+    };
+    Ok(Triad {
+        degree,
+        notes,
+        quality,
+    })
+}
+
+/// The modes of the major scale, in the order of the degree of the major scale on which they start.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Ionian,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Aeolian,
+    Locrian,
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mode::Ionian => write!(f, "Ionian"),
+            Mode::Dorian => write!(f, "Dorian"),
+            Mode::Phrygian => write!(f, "Phrygian"),
+            Mode::Lydian => write!(f, "Lydian"),
+            Mode::Mixolydian => write!(f, "Mixolydian"),
+            Mode::Aeolian => write!(f, "Aeolian"),
+            Mode::Locrian => write!(f, "Locrian"),
+        }
+    }
+}
+
+impl Mode {
+    /// Returns the degree of the major scale, starting at 1, on which the mode starts.
+    pub fn degree(&self) -> usize {
+        match self {
+            Mode::Ionian => 1,
+            Mode::Dorian => 2,
+            Mode::Phrygian => 3,
+            Mode::Lydian => 4,
+            Mode::Mixolydian => 5,
+            Mode::Aeolian => 6,
+            Mode::Locrian => 7,
+        }
+    }
+
+    /// Returns the tonic of the mode derived from the major scale of the given key.
+    pub fn tonic(&self, major_key: Note) -> Result<Note> {
+        Ok(ScaleType::Major.notes(major_key)?.notes[self.degree() - 1])
+    }
+
+    /// Returns the notes of the mode with the given tonic. The notes are spelled as in the major
+    /// scale from which the mode is derived.
+    pub fn notes(&self, tonic: Note) -> Result<Vec<Note>> {
+        for key in Note::all_keys(true) {
+            let mut notes = ScaleType::Major.notes(key)?.notes;
+            if notes[self.degree() - 1] == tonic {
+                notes.rotate_left(self.degree() - 1);
+                return Ok(notes);
+            }
+        }
+        Err(anyhow!(
+            "cannot find the major scale from which to derive {} {}",
+            tonic.to_string(),
+            self
+        ))
+    }
+}
+
+/// The natural notes in the order in which they appear in a scale starting from C.
+pub const NATURAL_NOTES: [NaturalNote; 7] = [
+    NaturalNote::C,
+    NaturalNote::D,
+    NaturalNote::E,
+    NaturalNote::F,
+    NaturalNote::G,
+    NaturalNote::A,
+    NaturalNote::B,
+];
+
+/// Returns the notes of the scale with the given tonic and intervals from the tonic, in semitones.
+/// Scales of seven notes are spelled using each letter once. Notes which would need a double
+/// accidental, and the notes of scales with a different number of notes, are spelled with the
+/// accidental of the tonic instead.
+pub fn spell_scale(tonic: Note, intervals: &[u8]) -> Vec<Note> {
+    if intervals.len() == 7 {
+        spell_notes(tonic, intervals, Some(&[0, 1, 2, 3, 4, 5, 6]))
+    } else {
+        spell_notes(tonic, intervals, None)
+    }
+}
+
+/// Returns the notes with the given intervals from the tonic. If given, `letters` contains the
+/// number of letters between the tonic and each note, which is used to spell the note. Otherwise,
+/// or if the note would need a double accidental, the note is spelled with the accidental of the
+/// tonic.
+fn spell_notes(tonic: Note, intervals: &[u8], letters: Option<&[usize]>) -> Vec<Note> {
+    let tonic_letter = NATURAL_NOTES
+        .iter()
+        .position(|letter| *letter == tonic.0)
+        .unwrap_or_default();
+    intervals
+        .iter()
+        .enumerate()
+        .map(|(index, interval)| {
+            let pitch = (semitones(tonic) + interval) % 12;
+            if let Some(offset) = letters.and_then(|letters| letters.get(index)) {
+                let letter = NATURAL_NOTES[(tonic_letter + offset) % 7];
+                match semitones_up(
+                    Note(letter, Accidental::Natural),
+                    note_from_semitones(pitch),
+                ) {
+                    0 => return Note(letter, Accidental::Natural),
+                    1 => return Note(letter, Accidental::Sharp),
+                    11 => return Note(letter, Accidental::Flat),
+                    _ => {}
+                }
+            }
+            match tonic.1 {
+                Accidental::Flat => note_from_semitones_flat(pitch),
+                _ => note_from_semitones(pitch),
+            }
+        })
+        .collect()
+}
+
+/// Scales which are neither defined by Trane nor modes of the major scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CustomScale {
+    /// The double harmonic, or Byzantine, scale.
+    DoubleHarmonic,
+
+    /// The symmetric diminished scale starting with a half step, played over dominant 7th chords.
+    DiminishedHalfWhole,
+
+    /// The symmetric diminished scale starting with a whole step, played over diminished 7th
+    /// chords.
+    DiminishedWholeHalf,
+
+    /// The Mixolydian mode with the added ♭3 of the blues scale, played over dominant 7th chords.
+    MixolydianBlues,
+
+    /// The Lydian dominant scale, the fourth mode of the melodic minor scale, played over
+    /// dominant 7♯11 chords.
+    LydianDominant,
+
+    /// The altered, or Superlocrian, scale, the seventh mode of the melodic minor scale, played
+    /// over altered dominant chords.
+    Altered,
+
+    /// The Aeolian dominant, or Spanish Phrygian, scale, the fifth mode of the harmonic minor
+    /// scale. It's the Phrygian mode with a major 3rd.
+    AeolianDominant,
+}
+
+impl Display for CustomScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomScale::DoubleHarmonic => write!(f, "Double Harmonic"),
+            CustomScale::DiminishedHalfWhole => write!(f, "Half-Whole Diminished"),
+            CustomScale::DiminishedWholeHalf => write!(f, "Whole-Half Diminished"),
+            CustomScale::MixolydianBlues => write!(f, "Mixolydian Blues"),
+            CustomScale::LydianDominant => write!(f, "Lydian Dominant"),
+            CustomScale::Altered => write!(f, "Altered"),
+            CustomScale::AeolianDominant => write!(f, "Aeolian Dominant"),
+        }
+    }
+}
+
+impl CustomScale {
+    /// Returns the intervals of the notes of the scale from the tonic, in semitones.
+    pub fn intervals(&self) -> &'static [u8] {
+        match self {
+            CustomScale::DoubleHarmonic => &[0, 1, 4, 5, 7, 8, 11],
+            CustomScale::DiminishedHalfWhole => &[0, 1, 3, 4, 6, 7, 9, 10],
+            CustomScale::DiminishedWholeHalf => &[0, 2, 3, 5, 6, 8, 9, 11],
+            CustomScale::MixolydianBlues => &[0, 2, 3, 4, 5, 7, 9, 10],
+            CustomScale::LydianDominant => &[0, 2, 4, 6, 7, 9, 10],
+            CustomScale::Altered => &[0, 1, 3, 4, 6, 8, 10],
+            CustomScale::AeolianDominant => &[0, 1, 4, 5, 7, 8, 10],
+        }
+    }
+
+    /// Returns the number of letters between the tonic and each note of the scale, used to spell
+    /// scales which don't have seven notes. Returns `None` if the default spelling of
+    /// `spell_scale` is used.
+    pub fn letters(&self) -> Option<&'static [usize]> {
+        match self {
+            // The ♭3 and the 3 are both spelled with the letter of the third degree.
+            CustomScale::MixolydianBlues => Some(&[0, 1, 2, 2, 3, 4, 5, 6]),
+            // The notes are spelled as the tones of a dominant chord and its alterations, so the
+            // ♭9 and the ♯9 share a letter and the 5th is skipped.
+            CustomScale::Altered => Some(&[0, 1, 1, 2, 3, 5, 6]),
+            _ => None,
+        }
+    }
+
+    /// Returns the notes which give the scale its character, given as their interval from the
+    /// tonic, in semitones, and a description of their role.
+    pub fn characteristic_notes(&self) -> &'static [(u8, &'static str)] {
+        match self {
+            CustomScale::MixolydianBlues => &[
+                (3, "the ♭3, the blue note in a major context"),
+                (10, "the ♭7, the characteristic note of the Mixolydian mode"),
+            ],
+            CustomScale::LydianDominant => &[
+                (6, "the ♯4, borrowed from the Lydian mode"),
+                (10, "the ♭7, which makes the scale dominant"),
+            ],
+            CustomScale::Altered => &[
+                (1, "the ♭9"),
+                (3, "the ♯9"),
+                (6, "the ♭5/♯11"),
+                (8, "the ♭13"),
+            ],
+            CustomScale::AeolianDominant => &[
+                (1, "the ♭2, borrowed from the Phrygian mode"),
+                (
+                    4,
+                    "the major 3rd above the root, which makes the scale dominant",
+                ),
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Returns the chord over which the scale is usually played, if any, given as the suffix of the
+    /// name of the chord and the intervals of its notes from the root, in semitones.
+    pub fn underlying_chord(&self) -> Option<(&'static str, &'static [u8])> {
+        match self {
+            CustomScale::DoubleHarmonic => None,
+            CustomScale::DiminishedHalfWhole => Some(("7", &[0, 4, 7, 10])),
+            CustomScale::DiminishedWholeHalf => Some(("°7", &[0, 3, 6, 9])),
+            CustomScale::MixolydianBlues => Some(("7", &[0, 4, 7, 10])),
+            CustomScale::LydianDominant => Some(("7♯11", &[0, 4, 6, 7, 10])),
+            CustomScale::Altered => None,
+            CustomScale::AeolianDominant => Some(("7", &[0, 4, 7, 10])),
+        }
+    }
+}
+
+/// Returns the pairs of consecutive notes in a scale of seven notes which are separated by an
+/// augmented second. Scales with a different number of notes have wider steps by design, so no
+/// pairs are returned for them.
+pub fn augmented_seconds(notes: &[Note]) -> Vec<(Note, Note)> {
+    if notes.len() != 7 {
+        return vec![];
+    }
+    (0..notes.len())
+        .map(|index| (notes[index], notes[(index + 1) % notes.len()]))
+        .filter(|(from, to)| semitones_up(*from, *to) == 3)
+        .collect()
+}
+
+/// A scale used in the guitar courses. Trane only defines a few of the most common scales, so this
+/// type extends them with the scales defined in this crate.
+#[derive(Clone, Copy, Debug)]
+pub enum GuitarScale {
+    /// One of the scales defined by Trane.
+    Trane(ScaleType),
+
+    /// One of the modes of the major scale.
+    Mode(Mode),
+
+    /// One of the scales defined in this crate.
+    Custom(CustomScale),
+}
+
+impl Display for GuitarScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuitarScale::Trane(scale) => write!(f, "{}", scale.to_string()),
+            GuitarScale::Mode(mode) => write!(f, "{}", mode),
+            GuitarScale::Custom(scale) => write!(f, "{}", scale),
+        }
+    }
+}
+
+impl From<ScaleType> for GuitarScale {
+    fn from(scale: ScaleType) -> Self {
+        GuitarScale::Trane(scale)
+    }
+}
+
+impl GuitarScale {
+    /// Returns the notes of the scale with the given tonic.
+    pub fn notes(&self, tonic: Note) -> Result<Vec<Note>> {
+        match self {
+            GuitarScale::Trane(scale) => Ok(scale.notes(tonic)?.notes),
+            GuitarScale::Mode(mode) => mode.notes(tonic),
+            GuitarScale::Custom(scale) => match scale.letters() {
+                None => Ok(spell_scale(tonic, scale.intervals())),
+                Some(letters) => Ok(spell_notes(tonic, scale.intervals(), Some(letters))),
+            },
+        }
+    }
+
+    /// Returns the chord over which the scale is usually played, if any. See
+    /// `CustomScale::underlying_chord`.
+    pub fn underlying_chord(&self) -> Option<(&'static str, &'static [u8])> {
+        match self {
+            GuitarScale::Custom(scale) => scale.underlying_chord(),
+            _ => None,
+        }
+    }
+
+    /// Returns the notes which give the scale its character. See
+    /// `CustomScale::characteristic_notes`.
+    pub fn characteristic_notes(&self) -> &'static [(u8, &'static str)] {
+        match self {
+            GuitarScale::Custom(scale) => scale.characteristic_notes(),
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::notes::Note;
+
+    use crate::music::{
+        augmented_seconds, diatonic_triad, respell_enharmonic, shortest_motion, CustomScale,
+        GuitarScale, Mode, NoteExt, TriadQuality,
+    };
+
+    #[test]
+    fn respelled_enharmonics() {
+        assert_eq!(respell_enharmonic(Note::C_FLAT), Note::B);
+        assert_eq!(respell_enharmonic(Note::E_SHARP), Note::F);
+        assert_eq!(respell_enharmonic(Note::A_SHARP), Note::B_FLAT);
+        assert_eq!(respell_enharmonic(Note::C_SHARP), Note::D_FLAT);
+        assert_eq!(respell_enharmonic(Note::G_FLAT), Note::F_SHARP);
+        assert_eq!(respell_enharmonic(Note::F_SHARP), Note::F_SHARP);
+        assert_eq!(respell_enharmonic(Note::E_FLAT), Note::E_FLAT);
+        assert_eq!(respell_enharmonic(Note::G), Note::G);
+    }
+
+    #[test]
+    fn diatonic_triads() -> anyhow::Result<()> {
+        let triad = diatonic_triad(Note::C, 2)?;
+        assert_eq!(triad.notes, vec![Note::D, Note::F, Note::A]);
+        assert_eq!(triad.quality, TriadQuality::Minor);
+        assert_eq!(triad.roman_numeral(), "ii");
+
+        let triad = diatonic_triad(Note::B_FLAT, 7)?;
+        assert_eq!(triad.notes, vec![Note::A, Note::C, Note::E_FLAT]);
+        assert_eq!(triad.quality, TriadQuality::Diminished);
+        assert_eq!(triad.roman_numeral(), "vii°");
+
+        assert!(diatonic_triad(Note::C, 8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn motion() {
+        assert_eq!(shortest_motion(Note::C, Note::D), 2);
+        assert_eq!(shortest_motion(Note::C, Note::B), -1);
+        assert_eq!(shortest_motion(Note::B_SHARP, Note::C), 0);
+        assert_eq!(shortest_motion(Note::C, Note::F_SHARP), 6);
+    }
+
+    #[test]
+    fn accidentals() {
+        assert_eq!(Note::F_SHARP.to_string_with_accidental(true), "G♭");
+        assert_eq!(Note::F_SHARP.to_string_with_accidental(false), "F♯");
+        assert_eq!(Note::B_FLAT.to_string_with_accidental(false), "A♯");
+        assert_eq!(Note::E_SHARP.to_string_with_accidental(true), "F");
+        assert_eq!(Note::C.to_string_with_accidental(true), "C");
+    }
+
+    #[test]
+    fn modes() -> anyhow::Result<()> {
+        assert_eq!(Mode::Dorian.tonic(Note::C)?, Note::D);
+        assert_eq!(
+            Mode::Dorian.notes(Note::D)?,
+            vec![
+                Note::D,
+                Note::E,
+                Note::F,
+                Note::G,
+                Note::A,
+                Note::B,
+                Note::C
+            ]
+        );
+        assert_eq!(Mode::Lydian.tonic(Note::F_SHARP)?, Note::B);
+        assert_eq!(Mode::Lydian.notes(Note::B)?[3], Note::E_SHARP);
+        assert!(Mode::Locrian.notes(Note::C_FLAT).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn custom_scales() -> anyhow::Result<()> {
+        let notes = GuitarScale::Custom(CustomScale::DoubleHarmonic).notes(Note::E)?;
+        assert_eq!(
+            notes,
+            vec![
+                Note::E,
+                Note::F,
+                Note::G_SHARP,
+                Note::A,
+                Note::B,
+                Note::C,
+                Note::D_SHARP
+            ]
+        );
+        assert_eq!(
+            augmented_seconds(&notes),
+            vec![(Note::F, Note::G_SHARP), (Note::C, Note::D_SHARP)]
+        );
+
+        // The diminished scales have eight notes, so they have no augmented seconds.
+        let notes = GuitarScale::Custom(CustomScale::DiminishedWholeHalf).notes(Note::A)?;
+        assert_eq!(notes.len(), 8);
+        assert!(augmented_seconds(&notes).is_empty());
+        assert_eq!(
+            GuitarScale::Custom(CustomScale::DiminishedHalfWhole)
+                .underlying_chord()
+                .map(|(suffix, _)| suffix),
+            Some("7")
+        );
+
+        let notes = GuitarScale::Custom(CustomScale::MixolydianBlues).notes(Note::B_FLAT)?;
+        assert_eq!(
+            notes,
+            vec![
+                Note::B_FLAT,
+                Note::C,
+                Note::D_FLAT,
+                Note::D,
+                Note::E_FLAT,
+                Note::F,
+                Note::G,
+                Note::A_FLAT
+            ]
+        );
+
+        // The altered scale is spelled as a dominant chord with its alterations.
+        let notes = GuitarScale::Custom(CustomScale::Altered).notes(Note::C)?;
+        assert_eq!(
+            notes,
+            vec![
+                Note::C,
+                Note::D_FLAT,
+                Note::D_SHARP,
+                Note::E,
+                Note::F_SHARP,
+                Note::A_FLAT,
+                Note::B_FLAT
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! Support for adding a practice log to each lesson, in which students can write down notes about
+//! their practice sessions.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use indoc::indoc;
+use trane::course_builder::{AssetBuilder, CourseBuilder};
+
+/// The name of the file containing the practice log of each lesson.
+pub const PRACTICE_LOG_FILE: &str = "practice_log.md";
+
+/// The template used for the practice logs when they are enabled from the command line.
+pub const DEFAULT_TEMPLATE: &str = indoc! {"
+    # Practice Log: {lesson_name}
+
+    Created on {date}.
+
+    ## Notes
+
+"};
+
+/// Returns the date with the given number of days since the Unix epoch in the format YYYY-MM-DD.
+fn format_date(days_since_epoch: i64) -> String {
+    // Converts the days to a date in the proleptic Gregorian calendar by splitting them into eras
+    // of 400 years, each of which starts on March 1st so that leap days fall at the end of a year.
+    let days = days_since_epoch + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Returns the current date in the format YYYY-MM-DD.
+fn today() -> Result<String> {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    Ok(format_date(seconds / 86_400))
+}
+
+/// Returns the contents of the practice log of a lesson by replacing the `{lesson_name}` and
+/// `{date}` placeholders in the template.
+fn fill_template(template: &str, lesson_name: &str, date: &str) -> String {
+    template
+        .replace("{lesson_name}", lesson_name)
+        .replace("{date}", date)
+}
+
+/// Adds a practice log to the assets of every lesson in the course, using the given template.
+/// Lessons which already have a practice log keep their own.
+pub fn add_practice_logs(course_builder: &mut CourseBuilder, template: &str) -> Result<()> {
+    let date = today()?;
+    for lesson_builder in &mut course_builder.lesson_builders {
+        if lesson_builder
+            .asset_builders
+            .iter()
+            .any(|asset_builder| asset_builder.file_name == PRACTICE_LOG_FILE)
+        {
+            continue;
+        }
+        let lesson_manifest =
+            (lesson_builder.manifest_closure)(course_builder.lesson_manifest_template.clone())
+                .build()?;
+        lesson_builder.asset_builders.push(AssetBuilder {
+            file_name: PRACTICE_LOG_FILE.to_string(),
+            contents: fill_template(template, &lesson_manifest.name, &date),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::practice_log::{fill_template, format_date};
+
+    #[test]
+    fn practice_log_contents() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(11_016), "2000-02-29");
+        assert_eq!(format_date(20_740), "2026-10-14");
+        assert_eq!(
+            fill_template("{lesson_name} ({date})", "Lesson", "2026-10-14"),
+            "Lesson (2026-10-14)"
+        );
+    }
+}
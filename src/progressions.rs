@@ -0,0 +1,2 @@
+pub mod modal_progressions;
+pub mod twelve_bar_blues;
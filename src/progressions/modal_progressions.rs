@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::modes,
+    music::{diatonic_triad, semitones_up, Mode, Triad, TriadQuality},
+    theory::voice_leading,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::progressions::modal");
+}
+
+/// The modes covered by the course, along with the degrees of the mode on which the chords of its
+/// characteristic progression are built, and the degree of its characteristic note.
+const MODE_PROGRESSIONS: [(Mode, &[usize], usize); 7] = [
+    (Mode::Ionian, &[1, 4, 5, 1], 7),
+    (Mode::Dorian, &[1, 4, 1], 6),
+    (Mode::Phrygian, &[1, 2, 1], 2),
+    (Mode::Lydian, &[1, 2, 1], 4),
+    (Mode::Mixolydian, &[1, 7, 4, 1], 7),
+    (Mode::Aeolian, &[1, 7, 6], 6),
+    (Mode::Locrian, &[1, 2, 1], 5),
+];
+
+/// The Roman numerals of the chords built on each number of semitones above the tonic.
+const NUMERALS: [&str; 12] = [
+    "I", "♭II", "II", "♭III", "III", "IV", "♯IV", "V", "♭VI", "VI", "♭VII", "VII",
+];
+
+/// Returns the Roman numeral of the triad relative to the tonic of the mode.
+fn roman_numeral(triad: &Triad, tonic: Note) -> String {
+    let numeral = NUMERALS[semitones_up(tonic, triad.root()) as usize];
+    match triad.quality {
+        TriadQuality::Major => numeral.to_string(),
+        TriadQuality::Minor => numeral.to_lowercase(),
+        TriadQuality::Diminished => format!("{}°", numeral.to_lowercase()),
+        TriadQuality::Augmented => format!("{}+", numeral),
+    }
+}
+
+/// Returns the ID of the lesson for the given mode and tonic.
+fn lesson_id(mode: Mode, tonic: Note) -> Ustr {
+    Ustr::from(&format!(
+        "{}::{}::{}",
+        *COURSE_ID,
+        mode.to_string().to_lowercase(),
+        tonic.to_string()
+    ))
+}
+
+/// Returns the ID of the course for the given mode.
+fn mode_course_id(mode: Mode) -> Ustr {
+    match mode {
+        Mode::Ionian => *modes::ionian::COURSE_ID,
+        Mode::Dorian => *modes::dorian::COURSE_ID,
+        Mode::Phrygian => *modes::phrygian::COURSE_ID,
+        Mode::Lydian => *modes::lydian::COURSE_ID,
+        Mode::Mixolydian => *modes::mixolydian::COURSE_ID,
+        Mode::Aeolian => *modes::aeolian::COURSE_ID,
+        Mode::Locrian => *modes::locrian::COURSE_ID,
+    }
+}
+
+/// Generates the exercise builders for the lesson of the given mode in the given major key. The
+/// first exercise asks for the progression and the second asks to improvise over it.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    mode: Mode,
+    major_key: Note,
+    degrees: &[usize],
+    characteristic_degree: usize,
+) -> Result<Vec<ExerciseBuilder>> {
+    let tonic = mode.tonic(major_key)?;
+    let characteristic_note = mode.notes(tonic)?[characteristic_degree - 1];
+    let triads = degrees
+        .iter()
+        .map(|degree| diatonic_triad(major_key, (mode.degree() + degree - 2) % 7 + 1))
+        .collect::<Result<Vec<Triad>>>()?;
+    let numerals = triads
+        .iter()
+        .map(|triad| roman_numeral(triad, tonic))
+        .collect::<Vec<String>>()
+        .join(" – ");
+    let chords = triads
+        .iter()
+        .map(|triad| triad.name())
+        .collect::<Vec<String>>()
+        .join(" – ");
+    let progression_answer = formatdoc! {"
+        The progression is {}, which in {} {} is played as:
+
+        {}
+
+        The chords are taken from the {} Major scale.
+    ", numerals, tonic.to_string(), mode, chords, major_key.to_string()};
+
+    Ok(vec![
+        ExerciseBuilder {
+            directory_name: "progression".to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        What is the characteristic chord progression of {} {}? Name its chords
+                        and play it.
+                    ", tonic.to_string(), mode},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: progression_answer.clone(),
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::progression", lesson_id))
+                    .name(format!(
+                        "The characteristic progression of {} {}",
+                        tonic.to_string(),
+                        mode
+                    ))
+                    .clone()
+            }),
+        },
+        ExerciseBuilder {
+            directory_name: "improvisation".to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Loop the characteristic chord progression of {} {} and improvise over
+                        it using the {} {} mode. Bring out the note which gives the mode its
+                        character.
+                    ", tonic.to_string(), mode, tonic.to_string(), mode},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        {}
+                        The characteristic note of the mode is {}, found on degree {} of the mode.
+                    ", progression_answer, characteristic_note.to_string(), characteristic_degree},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::improvisation", lesson_id))
+                    .name(format!(
+                        "Improvise over the progression of {} {}",
+                        tonic.to_string(),
+                        mode
+                    ))
+                    .clone()
+            }),
+        },
+    ])
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for (mode, degrees, characteristic_degree) in MODE_PROGRESSIONS {
+        for major_key in Note::all_keys(false) {
+            let tonic = mode.tonic(major_key)?;
+            let dependencies = major_key
+                .previous_key_in_circle()
+                .map(|previous_key| mode.tonic(previous_key))
+                .transpose()?
+                .map(|previous_tonic| lesson_id(mode, previous_tonic))
+                .into_iter()
+                .collect::<Vec<Ustr>>();
+            let lesson_id = lesson_id(mode, tonic);
+
+            lesson_builders.push(LessonBuilder {
+                directory_name: format!(
+                    "lesson_{}_{}",
+                    mode.to_string().to_lowercase(),
+                    tonic.to_ascii_string()
+                ),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(
+                    lesson_id,
+                    mode,
+                    major_key,
+                    degrees,
+                    characteristic_degree,
+                )?,
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("{} {} Progressions", tonic.to_string(), mode))
+                        .description(Some(format!(
+                            "Play the characteristic chord progression of {} {}.",
+                            tonic.to_string(),
+                            mode
+                        )))
+                        .dependencies(dependencies.clone())
+                        .metadata(Some(BTreeMap::from([
+                            (
+                                MusicMetadata::Key.to_string(),
+                                vec![tonic.to_ascii_string()],
+                            ),
+                            (
+                                MusicMetadata::ScaleType.to_string(),
+                                vec![mode.to_string().to_lowercase()],
+                            ),
+                        ])))
+                        .clone()
+                }),
+            });
+        }
+    }
+
+    let mut dependencies = vec![*voice_leading::COURSE_ID];
+    dependencies.extend(
+        MODE_PROGRESSIONS
+            .iter()
+            .map(|(mode, _, _)| mode_course_id(*mode)),
+    );
+    Ok(CourseBuilder {
+        directory_name: "modal_progressions".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Modal Chord Progressions".to_string(),
+            dependencies,
+            description: Some(
+                "Learn the chord progressions which bring out the sound of each mode.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chord progressions", "modes"])
+    .with_standard_instruction_asset(indoc! {"
+        Each mode of the major scale shares its chords with the major scale from which
+        it's derived, but a different chord acts as the tonic. Modal progressions
+        establish the sound of the mode by returning to its tonic chord often and by
+        using the chord which contains the characteristic note of the mode.
+
+        Avoid resolving to the tonic of the parent major scale, since doing so makes the
+        progression sound like it's in the major key instead of the mode.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        in_memory::build_course_in_memory, progressions::modal_progressions::course_builder,
+    };
+
+    /// Verifies that the course contains a lesson for each mode and key, with two exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 84);
+        assert_eq!(course.exercise_count(), 168);
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    chords::extended_voicings::{self, dominant_seventh_voicing},
+    constants::AUTHORS,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    progressions::modal_progressions,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::progressions::twelve_bar_blues");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(90),
+    difficulty: Some(Difficulty::Beginner),
+    backing_track_url: None,
+};
+
+/// The chords of the progression, given as their degree, the index of their root in the major
+/// scale, and the string on which their root is played in the suggested voicing. The roots of the
+/// IV7 and V7 chords are played in the fifth string so that all the chords are close together.
+const CHORDS: [(&str, usize, usize); 3] = [("I7", 0, 6), ("IV7", 3, 5), ("V7", 4, 5)];
+
+/// The degrees of the chords in each bar of the progression.
+const BARS: [&str; 12] = [
+    "I7", "I7", "I7", "I7", "IV7", "IV7", "I7", "I7", "V7", "IV7", "I7", "V7",
+];
+
+/// Returns the ID of the lesson for the given key.
+fn lesson_id(key: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()))
+}
+
+/// Returns a table with the chord played in each bar of the progression, four bars per row.
+fn progression_table(chord_names: &BTreeMap<&str, String>) -> String {
+    BARS.chunks(4)
+        .map(|row| {
+            format!(
+                "| {} |",
+                row.iter()
+                    .map(|degree| chord_names[degree].clone())
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Generates the exercise builders for the lesson in the given key.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let mut chord_names = BTreeMap::new();
+    let mut chords_table = vec![];
+    let mut voicings = vec![];
+    for (degree, scale_index, root_string) in CHORDS {
+        let root = scale_notes[scale_index];
+        let chord_name = format!("{}7", root.to_string());
+        let voicing = dominant_seventh_voicing(root, root_string).ok_or_else(|| {
+            anyhow!(
+                "no voicing of {} with the root on string {}",
+                chord_name,
+                root_string
+            )
+        })?;
+        chords_table.push(format!("| {} | {} |", degree, chord_name));
+        voicings.push(format!("{} ({}):\n\n{}", chord_name, degree, voicing));
+        chord_names.insert(degree, chord_name);
+    }
+
+    Ok(vec![ExerciseBuilder {
+        directory_name: "shuffle".to_string(),
+        asset_builders: vec![
+            AssetBuilder {
+                file_name: "front.md".to_string(),
+                contents: formatdoc! {"
+                    Play the rhythm of a 12-bar blues in {} with a shuffle feel.
+                ", key.to_string()},
+            },
+            AssetBuilder {
+                file_name: "back.md".to_string(),
+                contents: formatdoc! {"
+                    The 12-bar blues in {} uses three dominant seventh chords:
+
+                    | Degree | Chord |
+                    |--------|-------|
+                    {}
+
+                    Each bar of the progression is played with the following chord:
+
+                    {}
+
+                    Suggested voicings:
+
+                    {}
+                    The fingers are numbered from the index finger (1) to the little finger (4).
+                    Strings marked with an x are muted.
+                ",
+                key.to_string(),
+                chords_table.join("\n"),
+                progression_table(&chord_names),
+                voicings.join("\n")},
+            },
+        ],
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(format!("{}::shuffle", lesson_id))
+                .name(format!(
+                    "Shuffle over a 12-bar blues in {}",
+                    key.to_string()
+                ))
+                .clone()
+        }),
+    }])
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        let dependencies = key
+            .previous_key_in_circle()
+            .map(lesson_id)
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = lesson_id(key);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata =
+                    BTreeMap::from([(MusicMetadata::Key.to_string(), vec![key.to_ascii_string()])]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!("12-Bar Blues in {}", key.to_string()))
+                    .description(Some(format!(
+                        "Play the rhythm of a 12-bar blues in {}.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "twelve_bar_blues".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "12-Bar Blues Rhythm".to_string(),
+            dependencies: vec![
+                *extended_voicings::COURSE_ID,
+                *modal_progressions::COURSE_ID,
+            ],
+            description: Some(
+                "Play the rhythm guitar part of a 12-bar blues in all keys.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["chord progressions"])
+    .with_genre_metadata(&[Genre::Blues])
+    .with_standard_instruction_asset(indoc! {"
+        The 12-bar blues is the most common progression in the blues and in much of rock
+        and roll. It's built from the dominant seventh chords on the first, fourth, and
+        fifth degrees of the key.
+
+        Play it with a shuffle feel: split each beat into a long note and a short note,
+        as if playing the first and last notes of a triplet. Keep the strumming hand
+        moving evenly and lock in with the metronome before trying faster tempos.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        in_memory::build_course_in_memory, progressions::twelve_bar_blues::course_builder,
+    };
+
+    /// Verifies that the course contains a lesson for each key, with a single exercise each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+pub mod alternate_picking;
+pub mod artificial_harmonics;
+pub mod ear_training;
+pub mod economy_picking;
+pub mod expressive;
+pub mod fingerpicking;
+pub mod legato;
+pub mod natural_harmonics;
+pub mod rhythmic_scale_practice;
+pub mod sight_reading;
+pub mod string_skipping;
+pub mod sweep_picking;
+pub mod tapping;
+pub mod warmup;
@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
+        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, GuitarExerciseMetadata},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::alternate_picking");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(80),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// Returns a markdown table showing the pick direction of each note of the scale played one
+/// octave up and back down using strict alternate picking.
+fn picking_table(scale_notes: &[Note]) -> String {
+    // Play the scale up to the octave and then back down to the tonic.
+    let mut sequence: Vec<(usize, Note)> = scale_notes.iter().copied().enumerate().collect();
+    sequence.push((0, scale_notes[0]));
+    sequence.extend(scale_notes.iter().copied().enumerate().rev());
+
+    let degrees = sequence
+        .iter()
+        .map(|(degree, _)| (degree + 1).to_string())
+        .collect::<Vec<String>>();
+    let notes = sequence
+        .iter()
+        .map(|(_, note)| note.to_string())
+        .collect::<Vec<String>>();
+    let strokes = (0..sequence.len())
+        .map(|i| if i % 2 == 0 { "↓" } else { "↑" }.to_string())
+        .collect::<Vec<String>>();
+
+    formatdoc! {"
+        | Degree | {} |
+        |--------|{}
+        | Note   | {} |
+        | Pick   | {} |
+    ",
+    degrees.join(" | "),
+    "---|".repeat(sequence.len()),
+    notes.join(" | "),
+    strokes.join(" | ")}
+}
+
+/// Generates the exercise builders for the lesson in the given key.
+fn generate_exercise_builders(course_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale = ScaleType::Major;
+    let scale_notes = scale.notes(key)?.notes;
+    let table = picking_table(&scale_notes);
+
+    Ok(FretboardExplorationCourse::standard_tuning()
+        .into_iter()
+        .map(|guitar_string| ExerciseBuilder {
+            directory_name: format!(
+                "{}_{}_string",
+                guitar_string.open_note.to_ascii_string(),
+                guitar_string.string_number
+            ),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the {} {} scale in the {} string using strict alternate picking.
+
+                        Alternate between downstrokes and upstrokes on every note, including
+                        when the scale changes direction.
+                    ", key.to_string(), scale.to_string(), guitar_string},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The picking pattern for the {} {} scale, ascending and descending, is:
+
+                        {}
+                    ", key.to_string(), scale.to_string(), table},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!(
+                        "{}::{}::{}_{}_string",
+                        course_id,
+                        key.to_string(),
+                        guitar_string.open_note.to_string(),
+                        guitar_string.string_number,
+                    ))
+                    .name(format!(
+                        "Alternate picking on the {} {} scale in the {} string",
+                        key.to_string(),
+                        scale.to_string(),
+                        guitar_string
+                    ))
+                    .clone()
+            }),
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let course_generator = CircleFifthsCourse {
+        directory_name: "alternate_picking".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Alternate Picking".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID],
+            description: Some(
+                "Practice strict alternate picking on the major scale in all keys.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        course_asset_builders: vec![],
+        note_alias: None,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+        lesson_builder_generator: Box::new(move |note, previous_note| {
+            let lesson_id = format!("{}::{}", course_id, note.to_string());
+
+            Ok(LessonBuilder {
+                directory_name: format!("lesson_{}", note.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(course_id, note)?,
+                manifest_closure: Box::new(move |m| {
+                    let deps = match previous_note {
+                        None => vec![],
+                        Some(previous_note) => vec![Ustr::from(&format!(
+                            "{}::{}",
+                            course_id,
+                            previous_note.to_string()
+                        ))],
+                    };
+
+                    let mut metadata = BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![note.to_ascii_string()],
+                    )]);
+                    EXERCISE_METADATA.add_to(&mut metadata);
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", course_id, note.to_string()))
+                        .name(format!(
+                            "Alternate Picking on the {} Major Scale",
+                            note.to_string()
+                        ))
+                        .description(Some(format!(
+                            "Play the {} major scale using strict alternate picking.",
+                            note.to_string()
+                        )))
+                        .dependencies(deps)
+                        .metadata(Some(metadata))
+                        .clone()
+                }),
+            })
+        }),
+        extra_lessons_generator: None,
+    };
+    Ok(course_generator
+        .generate_course_builder()?
+        .with_instrument_metadata()
+        .with_skill_metadata(&["technique"])
+        .with_technique_metadata(&["alternate picking"])
+        .with_scale_metadata(ScaleType::Major)
+        .with_standard_instruction_asset(indoc! {"
+            Strict alternate picking means that every downstroke is followed by an upstroke
+            and every upstroke by a downstroke, no matter which note comes next.
+
+            Start slowly with a metronome and only increase the tempo when every note sounds
+            even in both volume and timing. Keep the motion of the picking hand small and
+            relaxed.
+        "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::alternate_picking::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 90);
+        Ok(())
+    }
+}
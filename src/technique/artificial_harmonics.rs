@@ -0,0 +1,215 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    metadata::Genre,
+    music::{note_from_semitones, semitones},
+    technique::natural_harmonics,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::artificial_harmonics");
+}
+
+/// The positions in which the pinch harmonics are played, given as their name, ID, and the fret of
+/// the fretted note.
+const POSITIONS: [(&str, &str, u8); 3] = [
+    ("Open Position", "open_position", 0),
+    ("5th Position", "5th_position", 5),
+    ("12th Position", "12th_position", 12),
+];
+
+/// The points at which the thumb can touch the string, given as the number of frets above the
+/// fretted note, the divisor of the vibrating length of the string, the number of semitones of the
+/// resulting pitch above the fretted note, and a description of that interval.
+const TOUCH_POINTS: [(u8, u8, u8, &str); 3] = [
+    (12, 2, 12, "one octave above"),
+    (19, 3, 19, "one octave and a perfect fifth above"),
+    (24, 4, 24, "two octaves above"),
+];
+
+/// The number of frets in the fretboard. Touch points beyond the last fret are over the pickups.
+const NUM_FRETS: u8 = 24;
+
+/// Returns a markdown table with the location and pitch of each touch point for the fretted note.
+fn touch_point_table(fret: u8, fretted_note: Note) -> String {
+    let rows = TOUCH_POINTS
+        .iter()
+        .map(|(distance, divisor, interval, description)| {
+            let touch_fret = fret + distance;
+            let location = if touch_fret <= NUM_FRETS {
+                format!("Over fret {}", touch_fret)
+            } else {
+                format!("Where fret {} would be, over the pickups", touch_fret)
+            };
+            format!(
+                "| {} | 1/{} | {}, {} {} |",
+                location,
+                divisor,
+                note_from_semitones(semitones(fretted_note) + interval).to_string(),
+                description,
+                fretted_note.to_string()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        | Touch point | Division | Pitch |
+        |-------------|----------|-------|
+        {}
+    ", rows}
+}
+
+/// Generates the exercise builders for the lesson of the given position. There's one exercise for
+/// each string.
+fn generate_exercise_builders(lesson_id: Ustr, fret: u8) -> Vec<ExerciseBuilder> {
+    tunings::STANDARD
+        .iter()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let fretted_note = note_from_semitones(semitones(guitar_string.open_note) + fret);
+            let fretted = if fret == 0 {
+                format!("the open string ({})", fretted_note.to_string())
+            } else {
+                format!("fret {} ({})", fret, fretted_note.to_string())
+            };
+            ExerciseBuilder {
+                directory_name: format!("string_{}", string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play a pinch harmonic on {} of string {}.
+                        ", fretted, string_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The fretted note shortens the vibrating part of the string to the
+                            section between fret {} and the bridge. The thumb must touch this
+                            section at one of the points that divide it into equal parts, which
+                            are found at the same distance from the fretted note as the natural
+                            harmonics are from the nut.
+
+                            {}
+                            If no harmonic sounds, move the picking hand slightly toward the neck
+                            or the bridge until it finds one of the touch points.
+                        ", fret, touch_point_table(fret, fretted_note)},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::string_{}", lesson_id, string_number))
+                        .name(format!(
+                            "Pinch harmonic at fret {} of string {}",
+                            fret, string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let mut previous_lesson: Option<Ustr> = None;
+    let lesson_builders = POSITIONS
+        .iter()
+        .map(|(name, id, fret)| {
+            let name = *name;
+            let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, id));
+            let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+            previous_lesson = Some(lesson_id);
+
+            LessonBuilder {
+                directory_name: format!("lesson_{}", id),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, *fret),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("Pinch Harmonics in the {}", name))
+                        .description(Some(format!(
+                            "Play pinch harmonics on every string in the {}.",
+                            name.to_lowercase()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "artificial_harmonics".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Pinch Harmonics".to_string(),
+            dependencies: vec![*natural_harmonics::COURSE_ID],
+            description: Some(
+                "Learn to play pinch harmonics on every string in common positions.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["artificial harmonics"])
+    .with_genre_metadata(&[Genre::Rock, Genre::Metal])
+    .with_standard_instruction_asset(indoc! {"
+        A pinch harmonic is played by choking up on the pick so that the side of the
+        thumb grazes the string right after the pick strikes it. The light touch of the
+        thumb stops the string from vibrating at its full length and brings out one of
+        its harmonics.
+
+        Pinch harmonics are easier to produce with some distortion and with the bridge
+        pickup. Dig into the string with the pick and let the thumb barely touch it.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        in_memory::build_course_in_memory, technique::artificial_harmonics::course_builder,
+    };
+
+    /// Verifies that the course contains a lesson for each position, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 3);
+        assert_eq!(course.exercise_count(), 18);
+        Ok(())
+    }
+}
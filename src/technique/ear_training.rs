@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, minor_scale},
+    instructions,
+    music::{note_from_semitones, semitones, semitones_up, GuitarScale, Mode},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr =
+        Ustr::from("trane::guitar::ear_training::scale_identification");
+}
+
+/// The strings and frets from which the scales are played, given as the string number, its open
+/// note, and the fret of the root.
+const ROOT_POSITIONS: [(usize, Note, u8); 2] = [(6, Note::E, 5), (5, Note::A, 3)];
+
+/// The names of the intervals from the root, indexed by their number of semitones.
+const INTERVAL_NAMES: [&str; 12] = [
+    "unison",
+    "minor 2nd",
+    "major 2nd",
+    "minor 3rd",
+    "major 3rd",
+    "perfect 4th",
+    "tritone",
+    "perfect 5th",
+    "minor 6th",
+    "major 6th",
+    "minor 7th",
+    "major 7th",
+];
+
+/// The groups of scales which must be told apart in each lesson, along with the ID of the lesson.
+/// Each scale in a group is compared against the first scale of the group.
+fn scale_groups() -> Vec<(&'static str, Vec<GuitarScale>)> {
+    vec![
+        (
+            "major_and_minor",
+            vec![
+                GuitarScale::Trane(ScaleType::Major),
+                GuitarScale::Trane(ScaleType::Minor),
+            ],
+        ),
+        (
+            "pentatonic",
+            vec![
+                GuitarScale::Trane(ScaleType::MajorPentatonic),
+                GuitarScale::Trane(ScaleType::MinorPentatonic),
+            ],
+        ),
+        (
+            "modes",
+            vec![
+                GuitarScale::Mode(Mode::Ionian),
+                GuitarScale::Mode(Mode::Dorian),
+                GuitarScale::Mode(Mode::Phrygian),
+                GuitarScale::Mode(Mode::Lydian),
+                GuitarScale::Mode(Mode::Mixolydian),
+                GuitarScale::Mode(Mode::Aeolian),
+                GuitarScale::Mode(Mode::Locrian),
+            ],
+        ),
+    ]
+}
+
+/// Returns the intervals of the notes of the scale from its root, in semitones.
+fn scale_intervals(scale: GuitarScale, root: Note) -> Result<Vec<u8>> {
+    Ok(scale
+        .notes(root)?
+        .iter()
+        .map(|note| semitones_up(root, *note))
+        .collect())
+}
+
+/// Returns the given intervals as a comma-separated list of their names.
+fn interval_list(intervals: &[u8]) -> String {
+    intervals
+        .iter()
+        .map(|interval| INTERVAL_NAMES[*interval as usize])
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Generates the exercise builders for the lesson with the given group of scales. There's one
+/// exercise for each scale and root position.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    scales: &[GuitarScale],
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_names = scales
+        .iter()
+        .map(|scale| scale.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let reference = scales[0];
+
+    let mut exercises = vec![];
+    for scale in scales {
+        let scale = *scale;
+        let scale_id = scale.to_string().to_lowercase().replace(' ', "_");
+        for (string_number, open_note, root_fret) in ROOT_POSITIONS {
+            let scale_id = scale_id.clone();
+            let root = note_from_semitones(semitones(open_note) + root_fret);
+            let intervals = scale_intervals(scale, root)?;
+            let reference_intervals = scale_intervals(reference, root)?;
+            let frets = intervals
+                .iter()
+                .chain([12].iter())
+                .map(|interval| (root_fret + interval).to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            let comparison = if scale.to_string() == reference.to_string() {
+                format!(
+                    "The other scales in this lesson are obtained by altering some of the \
+                    intervals of the {} scale, so use it as the reference when listening.",
+                    reference
+                )
+            } else {
+                let distinguishing = intervals
+                    .iter()
+                    .filter(|interval| !reference_intervals.contains(interval))
+                    .copied()
+                    .collect::<Vec<u8>>();
+                format!(
+                    "Compared to the {} scale, it's distinguished by the following intervals: \
+                    {}.",
+                    reference,
+                    interval_list(&distinguishing)
+                )
+            };
+
+            exercises.push(ExerciseBuilder {
+                directory_name: format!("{}_string_{}", scale_id, string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the following frets of string {} in order, or listen to a
+                            recording of a scale played from the same root:
+
+                            {}
+
+                            Which scale did you hear? Choose between the following: {}.
+                        ", string_number, frets, scale_names},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The scale is {} {}. Its intervals from the root are: {}.
+
+                            {}
+                        ",
+                        root.to_string(),
+                        scale,
+                        interval_list(&intervals[1..]),
+                        comparison},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!(
+                            "{}::{}::string_{}",
+                            lesson_id, scale_id, string_number
+                        ))
+                        .name(format!(
+                            "Identify the {} scale by ear from string {}",
+                            scale, string_number
+                        ))
+                        .clone()
+                }),
+            });
+        }
+    }
+    Ok(exercises)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut previous_lesson: Option<Ustr> = None;
+    let mut lesson_builders = vec![];
+    for (group_id, scales) in scale_groups() {
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, group_id));
+        let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+        previous_lesson = Some(lesson_id);
+        let scale_names = scales
+            .iter()
+            .map(|scale| scale.to_string().to_lowercase())
+            .collect::<Vec<String>>();
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", group_id),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Declarative)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, &scales)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!("Identify by Ear: {}", scale_names.join(", ")))
+                    .description(Some(format!(
+                        "Tell apart the following scales by ear: {}.",
+                        scale_names.join(", ")
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(BTreeMap::from([(
+                        MusicMetadata::ScaleType.to_string(),
+                        scale_names.clone(),
+                    )])))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "ear_training_scale_identification".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Ear Training: Scale Identification".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *minor_scale::COURSE_ID],
+            description: Some("Learn to identify the quality of a scale by ear.".to_string()),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["ear training"])
+    .with_concept_metadata(&["scales"])
+    .with_standard_instruction_asset(&format!(
+        "{}\n{}",
+        indoc! {"
+            The exercises in this course train you to recognize the quality of a scale
+            by its sound alone. Each exercise gives you a sequence of frets to play
+            without telling you which scale they belong to. You can also ask someone
+            else to play the scale for you or use a recording of it.
+
+            Listen to the scale and name it before flipping the card. Pay attention to
+            the intervals of each note from the root, especially the third, which tells
+            major and minor scales apart.
+        "},
+        instructions::self_assessment_guide()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::ear_training::course_builder};
+
+    /// Verifies that the course contains a lesson for each group of scales, with an exercise for
+    /// each scale in the group.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 3);
+        assert_eq!(course.exercise_count(), 22);
+        Ok(())
+    }
+}
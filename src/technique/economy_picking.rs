@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
+        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    music::semitones_up,
+    technique::alternate_picking,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::economy_picking");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(80),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// Returns the string, fret, and note of each note of the three-notes-per-string pattern of the
+/// scale starting on the root in the sixth string, from the lowest to the highest note.
+fn three_notes_per_string(scale_notes: &[Note]) -> Vec<(u8, u8, Note)> {
+    let lowest_string = tunings::STANDARD[0];
+    let mut pitch = lowest_string.pitch() + semitones_up(lowest_string.open_note, scale_notes[0]);
+    let mut pattern = vec![];
+    for (index, note) in scale_notes
+        .iter()
+        .cycle()
+        .take(tunings::STANDARD.len() * 3)
+        .enumerate()
+    {
+        if index > 0 {
+            let previous_note = scale_notes[(index - 1) % scale_notes.len()];
+            pitch += semitones_up(previous_note, *note);
+        }
+        let guitar_string = tunings::STANDARD[index / 3];
+        pattern.push((
+            guitar_string.string_number,
+            pitch - guitar_string.pitch(),
+            *note,
+        ));
+    }
+    pattern
+}
+
+/// Returns a markdown table with the string, fret, and pick direction of each note. The first note
+/// in each string is played by continuing the motion of the pick from the previous string, which
+/// results in every string starting with the same pick stroke.
+fn picking_table(pattern: &[(u8, u8, Note)], ascending: bool) -> String {
+    let strokes = if ascending {
+        ["↓", "↑", "↓"]
+    } else {
+        ["↑", "↓", "↑"]
+    };
+    let rows = pattern
+        .iter()
+        .enumerate()
+        .map(|(index, (string, fret, note))| {
+            format!(
+                "| {} | {} | {} | {} |",
+                string,
+                fret,
+                note.to_string(),
+                strokes[index % 3]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        | String | Fret | Note | Pick |
+        |--------|------|------|------|
+        {}
+    ", rows}
+}
+
+/// Generates the exercise builders for the lesson in the given key.
+fn generate_exercise_builders(course_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let ascending_pattern = three_notes_per_string(&ScaleType::Major.notes(key)?.notes);
+    let mut descending_pattern = ascending_pattern.clone();
+    descending_pattern.reverse();
+
+    Ok([
+        ("ascending", true, ascending_pattern),
+        ("descending", false, descending_pattern),
+    ]
+    .into_iter()
+    .map(|(direction, ascending, pattern)| ExerciseBuilder {
+        directory_name: direction.to_string(),
+        asset_builders: vec![
+            AssetBuilder {
+                file_name: "front.md".to_string(),
+                contents: formatdoc! {"
+                    Play the {} Major scale {} in the three-notes-per-string pattern starting
+                    from the 6th string using economy picking.
+                ", key.to_string(), direction},
+            },
+            AssetBuilder {
+                file_name: "back.md".to_string(),
+                contents: formatdoc! {"
+                    Alternate the pick strokes within each string and sweep into the next string
+                    in the direction the pick is already moving.
+
+                    {}
+                ", picking_table(&pattern, ascending)},
+            },
+        ],
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(format!("{}::{}::{}", course_id, key.to_string(), direction))
+                .name(format!(
+                    "Economy picking on the {} Major scale {}",
+                    key.to_string(),
+                    direction
+                ))
+                .clone()
+        }),
+    })
+    .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let course_generator = CircleFifthsCourse {
+        directory_name: "economy_picking".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Economy Picking".to_string(),
+            dependencies: vec![*alternate_picking::COURSE_ID],
+            description: Some(
+                "Play three-notes-per-string major scales in all keys using economy picking."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        course_asset_builders: vec![],
+        note_alias: None,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+        lesson_builder_generator: Box::new(move |note, previous_note| {
+            let lesson_id = format!("{}::{}", course_id, note.to_string());
+
+            Ok(LessonBuilder {
+                directory_name: format!("lesson_{}", note.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(course_id, note)?,
+                manifest_closure: Box::new(move |m| {
+                    let deps = match previous_note {
+                        None => vec![],
+                        Some(previous_note) => vec![Ustr::from(&format!(
+                            "{}::{}",
+                            course_id,
+                            previous_note.to_string()
+                        ))],
+                    };
+
+                    let mut metadata = BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![note.to_ascii_string()],
+                    )]);
+                    EXERCISE_METADATA.add_to(&mut metadata);
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", course_id, note.to_string()))
+                        .name(format!(
+                            "Economy Picking on the {} Major Scale",
+                            note.to_string()
+                        ))
+                        .description(Some(format!(
+                            "Play the {} major scale using economy picking.",
+                            note.to_string()
+                        )))
+                        .dependencies(deps)
+                        .metadata(Some(metadata))
+                        .clone()
+                }),
+            })
+        }),
+        extra_lessons_generator: None,
+    };
+    Ok(course_generator
+        .generate_course_builder()?
+        .with_instrument_metadata()
+        .with_skill_metadata(&["technique"])
+        .with_technique_metadata(&["economy picking"])
+        .with_scale_metadata(ScaleType::Major)
+        .with_standard_instruction_asset(indoc! {"
+            Economy picking combines alternate picking within a string with a small sweep
+            when crossing to the next string. With three notes in each string, every string
+            starts with a downstroke when ascending and with an upstroke when descending.
+
+            Let the pick rest on the next string after each sweep and keep the timing of
+            the swept notes as even as the rest.
+        "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::economy_picking::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key, with two exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 30);
+        Ok(())
+    }
+}
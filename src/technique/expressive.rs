@@ -0,0 +1,233 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::minor_pentatonic_scale,
+    music::{note_from_semitones, semitones},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::expressive");
+}
+
+/// The notes of the A minor pentatonic scale in the fifth position of the three highest strings,
+/// given as the string number, its name, the fret, and the note. These are the notes on which
+/// bends and vibrato are most commonly applied.
+const PENTATONIC_NOTES: [(usize, &str, u8, Note); 6] = [
+    (3, "G", 5, Note::C),
+    (3, "G", 7, Note::D),
+    (2, "B", 5, Note::E),
+    (2, "B", 8, Note::G),
+    (1, "high E", 5, Note::A),
+    (1, "high E", 8, Note::C),
+];
+
+/// The expressive techniques taught in the course in the order they are introduced.
+#[derive(Clone, Copy)]
+enum Technique {
+    Vibrato,
+    HalfStepBend,
+    WholeStepBend,
+    PreBend,
+}
+
+impl Technique {
+    /// Returns the name of the technique.
+    fn name(&self) -> &'static str {
+        match self {
+            Technique::Vibrato => "Vibrato",
+            Technique::HalfStepBend => "Half-step bend",
+            Technique::WholeStepBend => "Whole-step bend",
+            Technique::PreBend => "Pre-bend",
+        }
+    }
+
+    /// Returns the ID of the technique used in the lesson IDs and directory names.
+    fn id(&self) -> &'static str {
+        match self {
+            Technique::Vibrato => "vibrato",
+            Technique::HalfStepBend => "half_step_bend",
+            Technique::WholeStepBend => "whole_step_bend",
+            Technique::PreBend => "pre_bend",
+        }
+    }
+
+    /// Returns the explanation of the target pitch and motion for the given note and fret.
+    fn answer(&self, note: Note, fret: u8) -> String {
+        let half_step = note_from_semitones(semitones(note) + 1).to_string();
+        let whole_step = note_from_semitones(semitones(note) + 2).to_string();
+        match self {
+            Technique::Vibrato => formatdoc! {"
+                The pitch should oscillate slightly above {} and return to it in a steady
+                rhythm. Move the string up and down by rotating the wrist rather than by moving
+                the finger alone.
+            ", note.to_string()},
+            Technique::HalfStepBend => formatdoc! {"
+                The target pitch is {}, the same note found on fret {}. Push the string toward
+                the ceiling with the fretting finger supported by the fingers behind it until
+                the pitch rises one half step.
+            ", half_step, fret + 1},
+            Technique::WholeStepBend => formatdoc! {"
+                The target pitch is {}, the same note found on fret {}. Push the string with
+                two or three fingers working together and rotate the wrist until the pitch
+                rises one whole step.
+            ", whole_step, fret + 2},
+            Technique::PreBend => formatdoc! {"
+                Bend the string one whole step up to {} (the pitch of fret {}) without picking
+                it, then pick the string and slowly release the bend until the pitch returns to
+                {}.
+            ", whole_step, fret + 2, note.to_string()},
+        }
+    }
+}
+
+/// The techniques in the order in which their lessons are introduced.
+const TECHNIQUES: [Technique; 4] = [
+    Technique::Vibrato,
+    Technique::HalfStepBend,
+    Technique::WholeStepBend,
+    Technique::PreBend,
+];
+
+/// Generates the exercise builders for the lesson with the given technique.
+fn generate_exercise_builders(lesson_id: Ustr, technique: Technique) -> Vec<ExerciseBuilder> {
+    PENTATONIC_NOTES
+        .iter()
+        .map(|(string_number, string_name, fret, note)| {
+            let string_number = *string_number;
+            let fret = *fret;
+            let note = *note;
+            ExerciseBuilder {
+                directory_name: format!("string_{}_fret_{}", string_number, fret),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Technique: {}
+
+                            Apply the technique to the note {} on fret {} of the {} string.
+                        ", technique.name(), note.to_string(), fret, string_name},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: technique.answer(note, fret),
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!(
+                            "{}::string_{}_fret_{}",
+                            lesson_id, string_number, fret
+                        ))
+                        .name(format!(
+                            "{} on {} in string {}",
+                            technique.name(),
+                            note.to_string(),
+                            string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let mut previous_lesson: Option<Ustr> = None;
+    let lesson_builders = TECHNIQUES
+        .iter()
+        .map(|technique| {
+            let technique = *technique;
+            let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, technique.id()));
+            let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+            previous_lesson = Some(lesson_id);
+
+            LessonBuilder {
+                directory_name: format!("lesson_{}", technique.id()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, technique),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(technique.name().to_string())
+                        .description(Some(format!(
+                            "Apply the {} technique to the notes of the minor pentatonic scale.",
+                            technique.name().to_lowercase()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "expressive_techniques".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Vibrato and Bending".to_string(),
+            dependencies: vec![*minor_pentatonic_scale::COURSE_ID],
+            description: Some(
+                "Learn to apply vibrato and bends to the notes of the minor pentatonic scale."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["vibrato", "bends"])
+    .with_scale_metadata(ScaleType::MinorPentatonic)
+    .with_standard_instruction_asset(indoc! {"
+        The exercises in this course use the notes of the A minor pentatonic scale in
+        the fifth position, which is where bends and vibrato are most often used.
+
+        Always check the pitch of a bend against the target note by playing the target
+        note first. An out-of-tune bend is worse than no bend at all.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::expressive::course_builder};
+
+    /// Verifies that the course contains a lesson for each technique, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 4);
+        assert_eq!(course.exercise_count(), 24);
+        Ok(())
+    }
+}
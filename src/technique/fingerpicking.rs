@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType, LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    theory::basic_theory,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::fingerpicking");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Beginner),
+    backing_track_url: None,
+};
+
+/// An open chord shape used in the fingerpicking exercises.
+struct OpenChord {
+    /// The name of the chord.
+    name: &'static str,
+
+    /// The ID used in the exercise IDs and directory names.
+    id: &'static str,
+
+    /// The frets from the 6th to the 1st string. A value of `None` indicates the string is muted.
+    frets: [Option<u8>; 6],
+
+    /// The string which plays the root of the chord.
+    root_string: usize,
+
+    /// The string which the thumb alternates with the root in the Travis pattern.
+    alternate_bass_string: usize,
+}
+
+/// The open chords to which each fingerpicking pattern is applied.
+static OPEN_CHORDS: [OpenChord; 8] = [
+    OpenChord {
+        name: "C Major",
+        id: "C_major",
+        frets: [None, Some(3), Some(2), Some(0), Some(1), Some(0)],
+        root_string: 5,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "A Major",
+        id: "A_major",
+        frets: [None, Some(0), Some(2), Some(2), Some(2), Some(0)],
+        root_string: 5,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "G Major",
+        id: "G_major",
+        frets: [Some(3), Some(2), Some(0), Some(0), Some(0), Some(3)],
+        root_string: 6,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "E Major",
+        id: "E_major",
+        frets: [Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)],
+        root_string: 6,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "D Major",
+        id: "D_major",
+        frets: [None, None, Some(0), Some(2), Some(3), Some(2)],
+        root_string: 4,
+        alternate_bass_string: 5,
+    },
+    OpenChord {
+        name: "A Minor",
+        id: "A_minor",
+        frets: [None, Some(0), Some(2), Some(2), Some(1), Some(0)],
+        root_string: 5,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "E Minor",
+        id: "E_minor",
+        frets: [Some(0), Some(2), Some(2), Some(0), Some(0), Some(0)],
+        root_string: 6,
+        alternate_bass_string: 4,
+    },
+    OpenChord {
+        name: "D Minor",
+        id: "D_minor",
+        frets: [None, None, Some(0), Some(2), Some(3), Some(1)],
+        root_string: 4,
+        alternate_bass_string: 5,
+    },
+];
+
+/// The strings used by the thumb in each pattern.
+enum Bass {
+    /// The string which plays the root of the chord.
+    Root,
+
+    /// The string the thumb alternates with in the Travis pattern.
+    Alternate,
+}
+
+/// A string plucked by one of the fingers in a pattern.
+enum PatternString {
+    /// A bass string plucked by the thumb.
+    Bass(Bass),
+
+    /// One of the three highest strings, given by its number.
+    Treble(usize),
+}
+
+/// A fingerpicking pattern, given as a sequence of fingers and the strings they pluck.
+struct Pattern {
+    /// The name of the pattern.
+    name: &'static str,
+
+    /// The ID used in the lesson IDs and directory names.
+    id: &'static str,
+
+    /// A description of how the pattern should be played.
+    description: &'static str,
+
+    /// The sequence of fingers and strings they pluck.
+    steps: &'static [(&'static str, PatternString)],
+}
+
+/// The fingerpicking patterns taught in this course in the order they are introduced.
+static PATTERNS: [Pattern; 5] = [
+    Pattern {
+        name: "p-i-m-a",
+        id: "pima",
+        description: "Pluck the bass note with the thumb, then pluck the three highest strings \
+            in ascending order with the index, middle, and ring fingers.",
+        steps: &[
+            ("p", PatternString::Bass(Bass::Root)),
+            ("i", PatternString::Treble(3)),
+            ("m", PatternString::Treble(2)),
+            ("a", PatternString::Treble(1)),
+        ],
+    },
+    Pattern {
+        name: "p-i-m-i",
+        id: "pimi",
+        description: "Pluck the bass note with the thumb, then pluck the third, second, and \
+            third strings again with the index and middle fingers.",
+        steps: &[
+            ("p", PatternString::Bass(Bass::Root)),
+            ("i", PatternString::Treble(3)),
+            ("m", PatternString::Treble(2)),
+            ("i", PatternString::Treble(3)),
+        ],
+    },
+    Pattern {
+        name: "p-a-m-i",
+        id: "pami",
+        description: "Pluck the bass note with the thumb, then pluck the three highest strings \
+            in descending order with the ring, middle, and index fingers.",
+        steps: &[
+            ("p", PatternString::Bass(Bass::Root)),
+            ("a", PatternString::Treble(1)),
+            ("m", PatternString::Treble(2)),
+            ("i", PatternString::Treble(3)),
+        ],
+    },
+    Pattern {
+        name: "Travis picking",
+        id: "travis",
+        description: "Keep a steady alternating bass with the thumb on every beat while the \
+            index and middle fingers pluck the higher strings between the bass notes.",
+        steps: &[
+            ("p", PatternString::Bass(Bass::Root)),
+            ("i", PatternString::Treble(3)),
+            ("p", PatternString::Bass(Bass::Alternate)),
+            ("m", PatternString::Treble(2)),
+        ],
+    },
+    Pattern {
+        name: "Classical rest stroke",
+        id: "rest_stroke",
+        description: "Pluck the bass note with the thumb, then alternate the index and middle \
+            fingers on the first string using rest strokes. After each stroke, the finger \
+            should come to rest on the second string.",
+        steps: &[
+            ("p", PatternString::Bass(Bass::Root)),
+            ("i", PatternString::Treble(1)),
+            ("m", PatternString::Treble(1)),
+            ("i", PatternString::Treble(1)),
+        ],
+    },
+];
+
+/// Returns the chord shape in the standard notation (e.g., x32010 for C major).
+fn chord_shape(chord: &OpenChord) -> String {
+    chord
+        .frets
+        .iter()
+        .map(|fret| match fret {
+            None => "x".to_string(),
+            Some(fret) => fret.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Returns a markdown table with the finger and string of each step of the pattern.
+fn pattern_table(pattern: &Pattern, chord: &OpenChord) -> String {
+    let rows = pattern
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(index, (finger, string))| {
+            let string = match string {
+                PatternString::Bass(Bass::Root) => chord.root_string,
+                PatternString::Bass(Bass::Alternate) => chord.alternate_bass_string,
+                PatternString::Treble(string) => *string,
+            };
+            format!("| {} | {} | {} |", index + 1, finger, string)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        | Step | Finger | String |
+        |------|--------|--------|
+        {}
+    ", rows}
+}
+
+/// Generates the exercise builders for the lesson with the given pattern.
+fn generate_exercise_builders(lesson_id: Ustr, pattern: &'static Pattern) -> Vec<ExerciseBuilder> {
+    OPEN_CHORDS
+        .iter()
+        .map(|chord| ExerciseBuilder {
+            directory_name: chord.id.to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the {} pattern over the open {} chord ({}).
+                    ", pattern.name, chord.name, chord_shape(chord)},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        {}
+
+                        {}
+                        The fingers are named p (thumb), i (index), m (middle), and a (ring).
+                    ", pattern.description, pattern_table(pattern, chord)},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::{}", lesson_id, chord.id))
+                    .name(format!(
+                        "Play the {} pattern over {}",
+                        pattern.name, chord.name
+                    ))
+                    .clone()
+            }),
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let mut previous_lesson: Option<Ustr> = None;
+    let lesson_builders = PATTERNS
+        .iter()
+        .map(|pattern| {
+            let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, pattern.id));
+            let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+            previous_lesson = Some(lesson_id);
+
+            LessonBuilder {
+                directory_name: format!("lesson_{}", pattern.id),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, pattern),
+                manifest_closure: Box::new(move |m| {
+                    let mut metadata = BTreeMap::new();
+                    EXERCISE_METADATA.add_to(&mut metadata);
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("The {} Pattern", pattern.name))
+                        .description(Some(format!(
+                            "Apply the {} fingerpicking pattern to open chords.",
+                            pattern.name
+                        )))
+                        .dependencies(dependencies.clone())
+                        .metadata(Some(metadata))
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "fingerpicking".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Fingerpicking Patterns".to_string(),
+            dependencies: vec![*basic_theory::COURSE_ID],
+            description: Some(
+                "Learn common fingerpicking patterns by applying them to open chords.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["fingerpicking"])
+    .with_genre_metadata(&[Genre::Folk, Genre::Classical, Genre::Country, Genre::Pop])
+    .with_standard_instruction_asset(indoc! {"
+        Hold the chord with the fretting hand and repeat the pattern with the picking
+        hand until it flows without interruption. The thumb (p) plays the bass strings
+        and the index (i), middle (m), and ring (a) fingers play the higher strings.
+
+        Start slowly and only increase the tempo once every note of the pattern sounds
+        clearly and evenly.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::fingerpicking::course_builder};
+
+    /// Verifies that the course contains a lesson for each picking pattern, with eight exercises
+    /// each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 5);
+        assert_eq!(course.exercise_count(), 40);
+        Ok(())
+    }
+}
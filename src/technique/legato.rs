@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{
+        fretboard_exploration::FretboardExplorationCourse, major_pentatonic_scale,
+        minor_pentatonic_scale, note_aliases,
+    },
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    music::{circle_fifths_keys, semitones_up},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::legato");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(70),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// Returns the description of the legato movements needed to play the scale up one octave and back
+/// down in a single string.
+fn legato_answer(scale_notes: &[Note]) -> String {
+    let mut ascending = scale_notes.to_vec();
+    ascending.push(scale_notes[0]);
+
+    let up = ascending
+        .windows(2)
+        .map(|pair| {
+            format!(
+                "- {} to {}: hammer-on {} frets up.",
+                pair[0].to_string(),
+                pair[1].to_string(),
+                semitones_up(pair[0], pair[1])
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let down = ascending
+        .windows(2)
+        .rev()
+        .map(|pair| {
+            format!(
+                "- {} to {}: pull-off {} frets down.",
+                pair[1].to_string(),
+                pair[0].to_string(),
+                semitones_up(pair[0], pair[1])
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    formatdoc! {"
+        Pick the first note, {}, and play the rest of the scale without picking.
+
+        Ascending:
+
+        {}
+
+        Descending:
+
+        {}
+    ", scale_notes[0].to_string(), up, down}
+}
+
+/// Generates the exercise builders for the lesson with the given scale and key.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    scale: ScaleType,
+    key: Note,
+) -> Result<Vec<ExerciseBuilder>> {
+    let answer = legato_answer(&scale.notes(key)?.notes);
+    Ok(FretboardExplorationCourse::standard_tuning()
+        .into_iter()
+        .map(|guitar_string| ExerciseBuilder {
+            directory_name: format!(
+                "{}_{}_string",
+                guitar_string.open_note.to_ascii_string(),
+                guitar_string.string_number
+            ),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the {} {} scale in the {} string entirely legato. Only pick the
+                        first note, and use hammer-ons and pull-offs for the rest.
+                    ", key.to_string(), scale.to_string(), guitar_string},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: answer.clone(),
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!(
+                        "{}::{}_{}_string",
+                        lesson_id,
+                        guitar_string.open_note.to_string(),
+                        guitar_string.string_number,
+                    ))
+                    .name(format!(
+                        "Legato {} {} scale in the {} string",
+                        key.to_string(),
+                        scale.to_string(),
+                        guitar_string
+                    ))
+                    .clone()
+            }),
+        })
+        .collect())
+}
+
+/// Generates a lesson for each key of the given scale. The lessons follow the circle of fifths. The
+/// note alias is used to obtain the tonic of the scale from each major key.
+fn generate_lesson_builders(
+    scale: ScaleType,
+    scale_id: &str,
+    note_alias: fn(Note) -> Result<Note>,
+) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let mut lessons = vec![];
+    for (key, previous_key) in circle_fifths_keys() {
+        let note = note_alias(key)?;
+        let previous_note = previous_key.map(note_alias).transpose()?;
+        let lesson_id = Ustr::from(&format!(
+            "{}::{}::{}",
+            course_id,
+            scale_id,
+            note.to_string()
+        ));
+        let scale_id = scale_id.to_string();
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_{}_{}", scale_id, note.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, scale, note)?,
+            manifest_closure: Box::new(move |m| {
+                let deps = match previous_note {
+                    None => vec![],
+                    Some(previous_note) => vec![Ustr::from(&format!(
+                        "{}::{}::{}",
+                        course_id,
+                        scale_id,
+                        previous_note.to_string()
+                    ))],
+                };
+
+                let mut metadata = BTreeMap::from([
+                    (MusicMetadata::Key.to_string(), vec![note.to_ascii_string()]),
+                    (
+                        MusicMetadata::ScaleType.to_string(),
+                        vec![scale.to_string().to_lowercase()],
+                    ),
+                ]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Legato {} {} Scale",
+                        note.to_string(),
+                        scale.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} {} scale using only hammer-ons and pull-offs.",
+                        note.to_string(),
+                        scale.to_string()
+                    )))
+                    .dependencies(deps)
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = generate_lesson_builders(
+        ScaleType::MajorPentatonic,
+        "major_pentatonic",
+        note_aliases::no_alias,
+    )?;
+    lesson_builders.extend(generate_lesson_builders(
+        ScaleType::MinorPentatonic,
+        "minor_pentatonic",
+        note_aliases::relative_minor_alias,
+    )?);
+
+    Ok(CourseBuilder {
+        directory_name: "legato".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Legato Technique".to_string(),
+            dependencies: vec![
+                *major_pentatonic_scale::COURSE_ID,
+                *minor_pentatonic_scale::COURSE_ID,
+            ],
+            description: Some(
+                "Play the pentatonic scales in all keys using hammer-ons and pull-offs."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["hammer-ons", "pull-offs"])
+    .with_standard_instruction_asset(indoc! {"
+        Legato playing connects the notes without picking them individually. Only pick
+        the first note in each string and sound the rest with hammer-ons when ascending
+        and pull-offs when descending.
+
+        Each note should sound as loud and as long as the picked note. If a note dies
+        out, slow down and focus on the strength and accuracy of the fretting hand.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::legato::course_builder};
+
+    /// Verifies that the course contains a lesson for each key of the major and minor pentatonic
+    /// scales, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 30);
+        assert_eq!(course.exercise_count(), 180);
+        Ok(())
+    }
+}
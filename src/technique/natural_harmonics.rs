@@ -0,0 +1,173 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    music::{note_from_semitones, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::natural_harmonics");
+}
+
+/// A natural harmonic, given as the fret over which the string is touched, the divisor of the
+/// string length at that point, the number of semitones above the open string of the resulting
+/// pitch, and a description of that interval.
+const HARMONICS: [(u8, u8, u8, &str); 5] = [
+    (5, 4, 24, "two octaves above"),
+    (7, 3, 19, "one octave and a perfect fifth above"),
+    (9, 5, 28, "two octaves and a major third above"),
+    (12, 2, 12, "one octave above"),
+    (19, 3, 19, "one octave and a perfect fifth above"),
+];
+
+/// Generates the exercise builders for the lesson of the given string. There's one exercise for
+/// each of the harmonics.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    string_number: u8,
+    open_note: Note,
+) -> Vec<ExerciseBuilder> {
+    HARMONICS
+        .iter()
+        .map(|(fret, divisor, interval, description)| {
+            let fret = *fret;
+            let pitch = note_from_semitones(semitones(open_note) + interval);
+            ExerciseBuilder {
+                directory_name: format!("fret_{}", fret),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the natural harmonic over fret {} of string {} ({}). Which pitch
+                            does it produce?
+                        ", fret, string_number, open_note.to_string()},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The harmonic produces the note {}, {} the open string.
+
+                            Touching the string over fret {} divides it into {} equal parts, so the
+                            harmonic vibrates at {} times the frequency of the open string.
+                        ",
+                        pitch.to_string(),
+                        description,
+                        fret,
+                        divisor,
+                        divisor},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::fret_{}", lesson_id, fret))
+                        .name(format!(
+                            "Natural harmonic at fret {} of string {}",
+                            fret, string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let lesson_builders = tunings::STANDARD
+        .iter()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let lesson_id = Ustr::from(&format!("{}::string_{}", *COURSE_ID, string_number));
+
+            LessonBuilder {
+                directory_name: format!("lesson_string_{}", string_number),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(
+                    lesson_id,
+                    string_number,
+                    guitar_string.open_note,
+                ),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("Natural Harmonics in String {}", string_number))
+                        .description(Some(format!(
+                            "Identify the pitches of the natural harmonics in string {}.",
+                            string_number
+                        )))
+                        .dependencies(vec![])
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "natural_harmonics".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Natural Harmonics".to_string(),
+            dependencies: vec![],
+            description: Some(
+                "Learn to play and identify the natural harmonics in each string.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["natural harmonics"])
+    .with_standard_instruction_asset(indoc! {"
+        To play a natural harmonic, lightly touch the string directly over the fret wire
+        without pressing it down, pick the string, and lift the finger right after.
+
+        Natural harmonics occur at the points that divide the string into equal parts.
+        The smaller the part, the higher the pitch and the harder it is to make the
+        harmonic ring clearly.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::natural_harmonics::course_builder};
+
+    /// Verifies that the course contains a lesson for each string, with an exercise for each
+    /// harmonic.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 6);
+        assert_eq!(course.exercise_count(), 30);
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{fretboard_exploration::FretboardExplorationCourse, major_scale},
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    technique::alternate_picking,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::rhythmic_variations");
+}
+
+/// A rhythmic subdivision of the beat in which the scale is played.
+struct Subdivision {
+    /// The ID of the subdivision, used in the IDs and directories of the lessons.
+    id: &'static str,
+
+    /// The name of the subdivision.
+    name: &'static str,
+
+    /// The number of notes played in each beat, written out.
+    notes_per_beat: &'static str,
+
+    /// How the notes in each beat are counted out loud.
+    counting: &'static str,
+
+    /// The range of tempos, in beats per minute, suggested for this subdivision.
+    bpm_range: (u32, u32),
+}
+
+/// The subdivisions in the course, from the slowest to the fastest.
+const SUBDIVISIONS: [Subdivision; 4] = [
+    Subdivision {
+        id: "quarter_notes",
+        name: "Quarter Notes",
+        notes_per_beat: "one note",
+        counting: "1, 2, 3, 4",
+        bpm_range: (80, 120),
+    },
+    Subdivision {
+        id: "eighth_notes",
+        name: "Eighth Notes",
+        notes_per_beat: "two notes",
+        counting: "1 &, 2 &, 3 &, 4 &",
+        bpm_range: (70, 100),
+    },
+    Subdivision {
+        id: "eighth_note_triplets",
+        name: "Eighth-Note Triplets",
+        notes_per_beat: "three notes",
+        counting: "1 trip let, 2 trip let, 3 trip let, 4 trip let",
+        bpm_range: (60, 90),
+    },
+    Subdivision {
+        id: "sixteenth_notes",
+        name: "Sixteenth Notes",
+        notes_per_beat: "four notes",
+        counting: "1 e & a, 2 e & a, 3 e & a, 4 e & a",
+        bpm_range: (50, 80),
+    },
+];
+
+/// Returns the ID of the lesson for the given subdivision and key.
+fn lesson_id(subdivision: &Subdivision, key: Note) -> Ustr {
+    Ustr::from(&format!(
+        "{}::{}::{}",
+        *COURSE_ID,
+        subdivision.id,
+        key.to_string()
+    ))
+}
+
+/// Generates the exercise builders for the lesson with the given subdivision and key. There's one
+/// exercise for each string.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    subdivision: &'static Subdivision,
+    key: Note,
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let notes = scale_notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let (lowest_bpm, highest_bpm) = subdivision.bpm_range;
+
+    Ok(FretboardExplorationCourse::standard_tuning()
+        .into_iter()
+        .map(|guitar_string| ExerciseBuilder {
+            directory_name: format!(
+                "{}_{}_string",
+                guitar_string.open_note.to_ascii_string(),
+                guitar_string.string_number
+            ),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                            Play the {} major scale in the {} string in {}, up one octave and
+                            back down with a metronome.
+                        ",
+                    key.to_string(),
+                    guitar_string,
+                    subdivision.name.to_lowercase()},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                            The notes of the {} major scale are: {}.
+
+                            Play {} for each click of the metronome, counting {}.
+
+                            Set the metronome between {} and {} BPM. Start at {} BPM and only
+                            speed up once every note falls exactly on its subdivision.
+                        ",
+                    key.to_string(),
+                    notes,
+                    subdivision.notes_per_beat,
+                    subdivision.counting,
+                    lowest_bpm,
+                    highest_bpm,
+                    lowest_bpm},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!(
+                        "{}::{}_{}_string",
+                        lesson_id,
+                        guitar_string.open_note.to_string(),
+                        guitar_string.string_number,
+                    ))
+                    .name(format!(
+                        "Play the {} major scale in {} in the {} string",
+                        key.to_string(),
+                        subdivision.name.to_lowercase(),
+                        guitar_string
+                    ))
+                    .clone()
+            }),
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for (index, subdivision) in SUBDIVISIONS.iter().enumerate() {
+        let exercise_metadata = GuitarExerciseMetadata {
+            recommended_bpm: Some(subdivision.bpm_range.0),
+            difficulty: Some(if index < 2 {
+                Difficulty::Beginner
+            } else {
+                Difficulty::Intermediate
+            }),
+            backing_track_url: None,
+        };
+        for key in Note::all_keys(false) {
+            // Each lesson depends on the previous key in the circle of fifths and on the same key
+            // in the previous subdivision.
+            let previous_subdivision = index.checked_sub(1).map(|index| &SUBDIVISIONS[index]);
+            let dependencies = key
+                .previous_key_in_circle()
+                .map(|previous_key| lesson_id(subdivision, previous_key))
+                .into_iter()
+                .chain(previous_subdivision.map(|previous| lesson_id(previous, key)))
+                .collect::<Vec<Ustr>>();
+            let lesson_id = lesson_id(subdivision, key);
+            let mut metadata = BTreeMap::from([
+                (MusicMetadata::Key.to_string(), vec![key.to_ascii_string()]),
+                (
+                    MusicMetadata::ScaleType.to_string(),
+                    vec!["major".to_string()],
+                ),
+            ]);
+            exercise_metadata.add_to(&mut metadata);
+
+            lesson_builders.push(LessonBuilder {
+                directory_name: format!("lesson_{}_{}", subdivision.id, key.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, subdivision, key)?,
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!(
+                            "The {} Major Scale in {}",
+                            key.to_string(),
+                            subdivision.name
+                        ))
+                        .description(Some(format!(
+                            "Play the {} major scale in each string in {}.",
+                            key.to_string(),
+                            subdivision.name.to_lowercase()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .metadata(Some(metadata.clone()))
+                        .clone()
+                }),
+            });
+        }
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "rhythmic_variations".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Rhythmic Variations on the Major Scale".to_string(),
+            dependencies: vec![*alternate_picking::COURSE_ID, *major_scale::COURSE_ID],
+            description: Some(
+                "Play the major scale in all keys with different subdivisions of the beat."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique", "rhythm"])
+    .with_technique_metadata(&["alternate picking"])
+    .with_scale_metadata(ScaleType::Major)
+    .with_standard_instruction_asset(indoc! {"
+        Playing a scale evenly in quarter notes is easy, but playing it in triplets or
+        sixteenth notes at the same tempo is much harder. The lessons in this course go
+        through each subdivision of the beat, from quarter notes to sixteenth notes.
+
+        Always practice with a metronome and count the subdivisions out loud. Use strict
+        alternate picking, and lower the tempo whenever the notes stop falling exactly
+        on the subdivisions.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        in_memory::build_course_in_memory, technique::rhythmic_scale_practice::course_builder,
+    };
+
+    /// Verifies that the course contains a lesson for each subdivision and key, with six exercises
+    /// each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 48);
+        assert_eq!(course.exercise_count(), 288);
+        Ok(())
+    }
+}
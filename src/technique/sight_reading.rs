@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, positions},
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    music::{semitones, semitones_up},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::sight_reading");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// The fragments of the scale which are read in each lesson, given as their ID and the indices of
+/// their notes in the scale as played in the position.
+const FRAGMENTS: [(&str, &[usize]); 4] = [
+    ("ascending", &[0, 1, 2, 3, 4, 5, 6, 7]),
+    ("descending", &[12, 11, 10, 9, 8, 7, 6, 5]),
+    ("thirds", &[0, 2, 1, 3, 2, 4, 3, 5]),
+    ("skips", &[0, 4, 2, 5, 3, 7, 5, 9]),
+];
+
+/// Formats the given notes as a simple ASCII tab, with the lines of the strings from the highest to
+/// the lowest. Each note is given as the note, the number of its string, and its fret. All the
+/// notes are given the same duration, so they are evenly spaced in the tab.
+pub fn generate_tab_snippet(notes: &[(Note, u8, u8)]) -> String {
+    tunings::STANDARD
+        .iter()
+        .rev()
+        .map(|guitar_string| {
+            let columns = notes
+                .iter()
+                .map(|(_, note_string, fret)| {
+                    let fret = fret.to_string();
+                    if *note_string == guitar_string.string_number {
+                        format!("-{}", fret)
+                    } else {
+                        "-".repeat(fret.len() + 1)
+                    }
+                })
+                .collect::<String>();
+            let name = if guitar_string.string_number == 1 {
+                "e".to_string()
+            } else {
+                guitar_string.open_note.to_string()
+            };
+            format!("{}|{}-|", name, columns)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the notes of the major scale of the given key in the position which starts with the
+/// root in the sixth string, given as the note, the string number, and the fret.
+fn position_notes(key: Note) -> Result<Vec<(Note, u8, u8)>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let mut root_fret = semitones_up(Note::E, key);
+    if root_fret == 0 {
+        root_fret = 12;
+    }
+    let start_fret = root_fret - 1;
+
+    let mut notes = vec![];
+    let mut last_pitch = tunings::STANDARD[0].pitch() + root_fret - 1;
+    for guitar_string in tunings::STANDARD {
+        for fret in start_fret..=start_fret + 4 {
+            let pitch = guitar_string.pitch() + fret;
+            if pitch <= last_pitch {
+                continue;
+            }
+            if let Some(note) = scale_notes
+                .iter()
+                .find(|note| semitones(**note) == pitch % 12)
+            {
+                notes.push((*note, guitar_string.string_number, fret));
+                last_pitch = pitch;
+            }
+        }
+    }
+    Ok(notes)
+}
+
+/// Generates the exercise builders for the lesson of the given key. There's one exercise for each
+/// fragment.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let position = position_notes(key)?;
+    Ok(FRAGMENTS
+        .iter()
+        .map(|(fragment_id, indices)| {
+            let fragment_id = *fragment_id;
+            let fragment = indices
+                .iter()
+                .map(|index| position[*index])
+                .collect::<Vec<(Note, u8, u8)>>();
+            let note_names = fragment
+                .iter()
+                .map(|(note, _, _)| note.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            ExerciseBuilder {
+                directory_name: fragment_id.to_string(),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the following fragment at sight. Give each note the same
+                            duration and don't stop to correct mistakes.
+
+                            ```
+                            {}
+                            ```
+                        ", generate_tab_snippet(&fragment)},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The fragment is taken from the {} Major scale. Its notes are: {}.
+                        ", key.to_string(), note_names},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", lesson_id, fragment_id))
+                        .name(format!(
+                            "Sight-read a {} fragment of the {} Major scale",
+                            fragment_id,
+                            key.to_string()
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        let dependencies = key
+            .previous_key_in_circle()
+            .map(|previous_key| {
+                Ustr::from(&format!("{}::{}", *COURSE_ID, previous_key.to_string()))
+            })
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()));
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata = BTreeMap::from([
+                    (MusicMetadata::Key.to_string(), vec![key.to_ascii_string()]),
+                    (
+                        MusicMetadata::ScaleType.to_string(),
+                        vec!["major".to_string()],
+                    ),
+                ]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!("Sight-Reading in {} Major", key.to_string()))
+                    .description(Some(format!(
+                        "Read fragments of the {} major scale written in tablature.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "sight_reading".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Sight-Reading Scale Patterns".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *positions::COURSE_ID],
+            description: Some(
+                "Learn to play fragments of the major scale written in tablature at first sight."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_concept_metadata(&["scales"])
+    .with_technique_metadata(&["sight reading"])
+    .with_standard_instruction_asset(indoc! {"
+        Sight-reading is the skill of playing written music correctly the first time it's
+        seen. Each exercise shows a short fragment of the major scale written in
+        tablature. The top line of the tab is the first string (high E), and the numbers
+        are the frets to play.
+
+        Take a few seconds to look at the fragment before playing it, then play it at a
+        slow and steady tempo without stopping. The goal is to keep going even when a
+        mistake is made, so choose a tempo that allows you to read ahead. Once you can
+        read the tab easily, look away from the fretboard while playing.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::data::music::notes::Note;
+
+    use crate::{
+        in_memory::build_course_in_memory,
+        technique::sight_reading::{course_builder, generate_tab_snippet},
+    };
+
+    #[test]
+    fn tab_snippet() {
+        let tab = generate_tab_snippet(&[(Note::G, 6, 3), (Note::A, 6, 5), (Note::C, 5, 3)]);
+        assert_eq!(
+            tab,
+            [
+                "e|-------|",
+                "B|-------|",
+                "G|-------|",
+                "D|-------|",
+                "A|-----3-|",
+                "E|-3-5---|",
+            ]
+            .join("\n")
+        );
+    }
+
+    /// Verifies that the course contains a lesson for each key, with four exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 48);
+        Ok(())
+    }
+}
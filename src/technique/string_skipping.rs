@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
+        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::major_scale,
+    metadata::{Difficulty, GuitarExerciseMetadata},
+    music::note_to_fret,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::string_skipping");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(70),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// Returns the notes of the scale in the first twelve frets of the given string, sorted by fret.
+fn string_answer(string_number: u8, open_note: Note, scale_notes: &[Note]) -> String {
+    let mut frets = scale_notes
+        .iter()
+        .flat_map(|note| {
+            note_to_fret(open_note, *note)
+                .into_iter()
+                .filter(|fret| *fret <= 12)
+                .map(|fret| (fret, *note))
+        })
+        .collect::<Vec<(u8, Note)>>();
+    frets.sort_by_key(|(fret, _)| *fret);
+    let frets = frets
+        .iter()
+        .map(|(fret, note)| format!("{} (fret {})", note.to_string(), fret))
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!(
+        "- String {} ({}): {}.",
+        string_number,
+        open_note.to_string(),
+        frets
+    )
+}
+
+/// Generates the exercise builders for the lesson in the given key. There's one exercise for each
+/// pair of strings separated by a single string.
+fn generate_exercise_builders(course_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale = ScaleType::Major;
+    let scale_notes = scale.notes(key)?.notes;
+
+    Ok(tunings::STANDARD
+        .windows(3)
+        .map(|strings| {
+            let (low_number, low_note) = (strings[0].string_number, strings[0].open_note);
+            let skipped_number = strings[1].string_number;
+            let (high_number, high_note) = (strings[2].string_number, strings[2].open_note);
+            ExerciseBuilder {
+                directory_name: format!("strings_{}_and_{}", low_number, high_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} {} scale using only strings {} and {}, skipping string
+                            {}. Alternate between both strings as you move up and down the
+                            fretboard.
+                        ",
+                        key.to_string(), scale.to_string(), low_number, high_number,
+                        skipped_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The notes of the {} {} scale in both strings are:
+
+                            {}
+                            {}
+                        ",
+                        key.to_string(),
+                        scale.to_string(),
+                        string_answer(low_number, low_note, &scale_notes),
+                        string_answer(high_number, high_note, &scale_notes)},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!(
+                            "{}::{}::strings_{}_and_{}",
+                            course_id,
+                            key.to_string(),
+                            low_number,
+                            high_number
+                        ))
+                        .name(format!(
+                            "Play the {} {} scale in strings {} and {}",
+                            key.to_string(),
+                            scale.to_string(),
+                            low_number,
+                            high_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let course_generator = CircleFifthsCourse {
+        directory_name: "string_skipping".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "String Skipping".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID],
+            description: Some(
+                "Play the major scale in all keys while skipping strings.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        course_asset_builders: vec![],
+        note_alias: None,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+        lesson_builder_generator: Box::new(move |note, previous_note| {
+            let lesson_id = format!("{}::{}", course_id, note.to_string());
+
+            Ok(LessonBuilder {
+                directory_name: format!("lesson_{}", note.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(course_id, note)?,
+                manifest_closure: Box::new(move |m| {
+                    let deps = match previous_note {
+                        None => vec![],
+                        Some(previous_note) => vec![Ustr::from(&format!(
+                            "{}::{}",
+                            course_id,
+                            previous_note.to_string()
+                        ))],
+                    };
+
+                    let mut metadata = BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![note.to_ascii_string()],
+                    )]);
+                    EXERCISE_METADATA.add_to(&mut metadata);
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", course_id, note.to_string()))
+                        .name(format!(
+                            "String Skipping on the {} Major Scale",
+                            note.to_string()
+                        ))
+                        .description(Some(format!(
+                            "Play the {} major scale while skipping strings.",
+                            note.to_string()
+                        )))
+                        .dependencies(deps)
+                        .metadata(Some(metadata))
+                        .clone()
+                }),
+            })
+        }),
+        extra_lessons_generator: None,
+    };
+    Ok(course_generator
+        .generate_course_builder()?
+        .with_instrument_metadata()
+        .with_skill_metadata(&["technique"])
+        .with_technique_metadata(&["string skipping"])
+        .with_scale_metadata(ScaleType::Major)
+        .with_standard_instruction_asset(indoc! {"
+            String skipping breaks up linear scale runs into wider leaps. Play the scale
+            using only the two given strings and mute the string in between to avoid
+            unwanted noise.
+
+            Focus on the accuracy of the picking hand when jumping over the skipped string.
+        "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::string_skipping::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key, with four exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 60);
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, minor_scale, note_aliases},
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::{semitones, semitones_up},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::sweep_picking");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Expert),
+    backing_track_url: None,
+};
+
+/// The number of strings in each of the sweep shapes.
+const STRING_GROUPS: [usize; 3] = [3, 4, 5];
+
+/// Returns the string and fret of each note of the sweep shape that starts with the root of the
+/// triad in the lowest string of the group and continues with the next chord tone in each string.
+fn sweep_shape(triad: &[Note], num_strings: usize) -> Vec<(u8, u8, Note)> {
+    let strings = &tunings::STANDARD[tunings::STANDARD.len() - num_strings..];
+    let pitch_classes = triad.iter().map(|n| semitones(*n)).collect::<Vec<u8>>();
+
+    let first_fret = semitones_up(strings[0].open_note, triad[0]);
+    let mut pitch = strings[0].pitch() + first_fret;
+    let mut shape = vec![(strings[0].string_number, first_fret, triad[0])];
+
+    for guitar_string in &strings[1..] {
+        // Find the next chord tone above the previous note that can be played in this string.
+        let open_pitch = guitar_string.pitch();
+        pitch += 1;
+        while !pitch_classes.contains(&(pitch % 12)) || pitch < open_pitch {
+            pitch += 1;
+        }
+        let index = pitch_classes.iter().position(|p| *p == pitch % 12).unwrap();
+        shape.push((
+            guitar_string.string_number,
+            pitch - open_pitch,
+            triad[index],
+        ));
+    }
+    shape
+}
+
+/// Generates the exercise builders for the lesson with the given triad.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    quality: &'static str,
+    root: Note,
+    triad: &[Note],
+) -> Vec<ExerciseBuilder> {
+    let triad_notes = triad
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    STRING_GROUPS
+        .iter()
+        .map(|num_strings| {
+            let num_strings = *num_strings;
+            let shape = sweep_shape(triad, num_strings);
+            let lowest = shape.first().unwrap().0;
+            let highest = shape.last().unwrap().0;
+            let frets = shape
+                .iter()
+                .map(|(number, fret, note)| {
+                    format!("- String {}: fret {} ({})", number, fret, note.to_string())
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            ExerciseBuilder {
+                directory_name: format!("{}_strings", num_strings),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Sweep the {} {} triad arpeggio across {} strings, from string {} to
+                            string {}.
+                        ", root.to_string(), quality, num_strings, lowest, highest},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The notes of the {} {} triad are: {}.
+
+                            One shape for the arpeggio is:
+
+                            {}
+
+                            Sweep down (↓) from string {} to string {} when ascending, and sweep up
+                            (↑) from string {} to string {} when descending.
+                        ",
+                        root.to_string(),
+                        quality,
+                        triad_notes,
+                        frets,
+                        lowest,
+                        highest,
+                        highest,
+                        lowest},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}_strings", lesson_id, num_strings))
+                        .name(format!(
+                            "Sweep the {} {} triad across {} strings",
+                            root.to_string(),
+                            quality,
+                            num_strings
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Generates a lesson for each of the twelve keys using triads of the given quality. The note alias
+/// is used to obtain the root of the triad from each major key.
+fn generate_lesson_builders(
+    scale: ScaleType,
+    quality: &'static str,
+    note_alias: fn(Note) -> Result<Note>,
+) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let quality_id = quality.to_lowercase();
+    let mut lessons = vec![];
+    for key in Note::all_keys(false) {
+        let root = note_alias(key)?;
+        let previous_root = key.previous_key_in_circle().map(note_alias).transpose()?;
+        let scale_notes = scale.notes(root)?.notes;
+        let triad = vec![scale_notes[0], scale_notes[2], scale_notes[4]];
+        let lesson_id = Ustr::from(&format!(
+            "{}::{}::{}",
+            course_id,
+            quality_id,
+            root.to_string()
+        ));
+        let quality_id = quality_id.clone();
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_{}_{}", quality_id, root.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, quality, root, &triad),
+            manifest_closure: Box::new(move |m| {
+                let deps = match previous_root {
+                    None => vec![],
+                    Some(previous_root) => vec![Ustr::from(&format!(
+                        "{}::{}::{}",
+                        course_id,
+                        quality_id,
+                        previous_root.to_string()
+                    ))],
+                };
+
+                let mut metadata = BTreeMap::from([(
+                    MusicMetadata::Key.to_string(),
+                    vec![root.to_ascii_string()],
+                )]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Sweep Picking the {} {} Triad",
+                        root.to_string(),
+                        quality
+                    ))
+                    .description(Some(format!(
+                        "Sweep the {} {} triad across three, four, and five strings.",
+                        root.to_string(),
+                        quality
+                    )))
+                    .dependencies(deps)
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders =
+        generate_lesson_builders(ScaleType::Major, "Major", note_aliases::no_alias)?;
+    lesson_builders.extend(generate_lesson_builders(
+        ScaleType::Minor,
+        "Minor",
+        note_aliases::relative_minor_alias,
+    )?);
+
+    Ok(CourseBuilder {
+        directory_name: "sweep_picking".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Sweep Picking".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *minor_scale::COURSE_ID],
+            description: Some(
+                "Sweep major and minor triad arpeggios across three, four, and five strings."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["sweep picking"])
+    .with_concept_metadata(&["arpeggios"])
+    .with_genre_metadata(&[Genre::Metal])
+    .with_standard_instruction_asset(indoc! {"
+        Sweep picking plays an arpeggio with a single continuous motion of the pick
+        across the strings, instead of picking each note separately.
+
+        Let the pick fall through the strings while the fretting hand plays each note
+        and mutes it as soon as the next note sounds. The notes should sound one at a
+        time and not ring together like a strummed chord.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::sweep_picking::course_builder};
+
+    /// Verifies that the course contains a lesson for each major and minor key, with three
+    /// exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 24);
+        assert_eq!(course.exercise_count(), 72);
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::note_aliases,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::{circle_fifths_keys, semitones_up},
+    technique::legato,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::tapping");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Advanced),
+    backing_track_url: None,
+};
+
+/// The number of notes played by the fretting hand in each pattern.
+const FRETTED_NOTES: usize = 2;
+
+/// A tapping pattern, given by the fingers of the picking hand used to tap the notes.
+struct TapPattern {
+    /// The name of the pattern.
+    name: &'static str,
+
+    /// The ID used in the exercise IDs and directory names.
+    id: &'static str,
+
+    /// The fingers of the picking hand used to tap, in the order they tap the notes.
+    fingers: &'static [&'static str],
+}
+
+/// The tapping patterns taught in each lesson.
+static TAP_PATTERNS: [TapPattern; 3] = [
+    TapPattern {
+        name: "One-finger tapping",
+        id: "one_finger",
+        fingers: &["m"],
+    },
+    TapPattern {
+        name: "Two-finger tapping",
+        id: "two_finger",
+        fingers: &["m", "a"],
+    },
+    TapPattern {
+        name: "Three-finger tapping",
+        id: "three_finger",
+        fingers: &["m", "a", "c"],
+    },
+];
+
+/// Returns the full names of the given picking hand fingers.
+fn finger_names(fingers: &[&str]) -> String {
+    fingers
+        .iter()
+        .map(|finger| match *finger {
+            "m" => "middle (m)",
+            "a" => "ring (a)",
+            _ => "little (c)",
+        })
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Returns a markdown table with the fret, note, and technique of each note of the pattern played
+/// in the first string. The fretting hand plays the lowest notes of the scale starting from the
+/// root, and the taps continue with the next notes of the scale. The pattern goes up to the highest
+/// tap and back down using pull-offs.
+fn tapping_table(pattern: &TapPattern, scale_notes: &[Note]) -> String {
+    let num_notes = FRETTED_NOTES + pattern.fingers.len();
+    let mut fret = semitones_up(Note::E, scale_notes[0]);
+    let mut notes = vec![(fret, scale_notes[0])];
+    for index in 1..num_notes {
+        let previous_note = scale_notes[(index - 1) % scale_notes.len()];
+        let note = scale_notes[index % scale_notes.len()];
+        fret += semitones_up(previous_note, note);
+        notes.push((fret, note));
+    }
+
+    let mut rows = vec![];
+    for (index, (fret, note)) in notes.iter().enumerate() {
+        let technique = match index {
+            0 => "Fretting hand (picked)".to_string(),
+            _ if index < FRETTED_NOTES => "Fretting hand (hammer-on)".to_string(),
+            _ => format!("Tap ({})", pattern.fingers[index - FRETTED_NOTES]),
+        };
+        rows.push((*fret, *note, technique));
+    }
+    for (index, (fret, note)) in notes.iter().enumerate().rev().skip(1) {
+        let technique = if index < FRETTED_NOTES {
+            "Fretting hand (pull-off)".to_string()
+        } else {
+            format!("Tap ({}, pull-off)", pattern.fingers[index - FRETTED_NOTES])
+        };
+        rows.push((*fret, *note, technique));
+    }
+
+    let rows = rows
+        .iter()
+        .enumerate()
+        .map(|(index, (fret, note, technique))| {
+            format!(
+                "| {} | {} | {} | {} |",
+                index + 1,
+                fret,
+                note.to_string(),
+                technique
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    formatdoc! {"
+        | Step | Fret | Note | Technique |
+        |------|------|------|-----------|
+        {}
+    ", rows}
+}
+
+/// Generates the exercise builders for the lesson with the given scale and key. There's one
+/// exercise for each tapping pattern.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    scale: ScaleType,
+    key: Note,
+) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = scale.notes(key)?.notes;
+    Ok(TAP_PATTERNS
+        .iter()
+        .map(|pattern| ExerciseBuilder {
+            directory_name: pattern.id.to_string(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Pattern: {}
+
+                        Tapping fingers: {}
+
+                        Play the {} {} scale in the 1st string starting from the root. Fret the
+                        first two notes with the fretting hand and tap the rest.
+                    ",
+                    pattern.name,
+                    finger_names(pattern.fingers),
+                    key.to_string(),
+                    scale.to_string()},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The fret sequence for the pattern is:
+
+                        {}
+                        The picking hand fingers are named m (middle), a (ring), and c (little).
+                    ", tapping_table(pattern, &scale_notes)},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::{}", lesson_id, pattern.id))
+                    .name(format!(
+                        "{} on the {} {} scale",
+                        pattern.name,
+                        key.to_string(),
+                        scale.to_string()
+                    ))
+                    .clone()
+            }),
+        })
+        .collect())
+}
+
+/// Generates a lesson for each key of the given scale. The lessons follow the circle of fifths. The
+/// note alias is used to obtain the tonic of the scale from each major key.
+fn generate_lesson_builders(
+    scale: ScaleType,
+    scale_id: &str,
+    note_alias: fn(Note) -> Result<Note>,
+) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let mut lessons = vec![];
+    for (key, previous_key) in circle_fifths_keys() {
+        let note = note_alias(key)?;
+        let previous_note = previous_key.map(note_alias).transpose()?;
+        let lesson_id = Ustr::from(&format!(
+            "{}::{}::{}",
+            course_id,
+            scale_id,
+            note.to_string()
+        ));
+        let scale_id = scale_id.to_string();
+
+        lessons.push(LessonBuilder {
+            directory_name: format!("lesson_{}_{}", scale_id, note.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, scale, note)?,
+            manifest_closure: Box::new(move |m| {
+                let deps = match previous_note {
+                    None => vec![],
+                    Some(previous_note) => vec![Ustr::from(&format!(
+                        "{}::{}::{}",
+                        course_id,
+                        scale_id,
+                        previous_note.to_string()
+                    ))],
+                };
+
+                let mut metadata = BTreeMap::from([
+                    (MusicMetadata::Key.to_string(), vec![note.to_ascii_string()]),
+                    (
+                        MusicMetadata::ScaleType.to_string(),
+                        vec![scale.to_string().to_lowercase()],
+                    ),
+                ]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "Tapping the {} {} Scale",
+                        note.to_string(),
+                        scale.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play the {} {} scale using one, two, and three tapping fingers.",
+                        note.to_string(),
+                        scale.to_string()
+                    )))
+                    .dependencies(deps)
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+    Ok(lessons)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = generate_lesson_builders(
+        ScaleType::MinorPentatonic,
+        "minor_pentatonic",
+        note_aliases::relative_minor_alias,
+    )?;
+    lesson_builders.extend(generate_lesson_builders(
+        ScaleType::Major,
+        "major",
+        note_aliases::no_alias,
+    )?);
+
+    Ok(CourseBuilder {
+        directory_name: "tapping".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Two-Hand Tapping".to_string(),
+            dependencies: vec![*legato::COURSE_ID],
+            description: Some(
+                "Extend single-string runs of the pentatonic and major scales with tapping."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["tapping"])
+    .with_genre_metadata(&[Genre::Rock, Genre::Metal])
+    .with_standard_instruction_asset(indoc! {"
+        Two-hand tapping uses the fingers of the picking hand to hammer notes onto the
+        fretboard. Tap firmly right behind the fret and pull the tapping finger slightly
+        toward the floor when releasing it to sound the note below.
+
+        Mute the unused strings with the palm of the picking hand and the spare fingers
+        of the fretting hand to avoid unwanted noise.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, technique::tapping::course_builder};
+
+    /// Verifies that the course contains a lesson for each key of the major and minor pentatonic
+    /// scales, with three exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 30);
+        assert_eq!(course.exercise_count(), 90);
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType, LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    metadata::{Difficulty, GuitarExerciseMetadata},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::technique::warmup");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(60),
+    difficulty: Some(Difficulty::Beginner),
+    backing_track_url: None,
+};
+
+/// The frets from which each pattern is played.
+const START_FRETS: [u8; 3] = [1, 5, 9];
+
+/// A chromatic pattern used to warm up the fingers.
+struct WarmupPattern {
+    /// The ID of the pattern, used in the IDs and directories of the lesson.
+    id: &'static str,
+
+    /// The name of the pattern.
+    name: &'static str,
+
+    /// The description of the pattern shown in the front of each card.
+    description: &'static str,
+
+    /// The fingers of the fretting hand in the order they are played in each string.
+    fingers: &'static [u8],
+
+    /// The pick strokes for the fingers in each string.
+    pick_strokes: &'static str,
+
+    /// Optional remarks about the fingering added to the back of each card.
+    remarks: Option<&'static str>,
+}
+
+/// The patterns in the course, in the order in which they should be practiced.
+const PATTERNS: [WarmupPattern; 3] = [
+    WarmupPattern {
+        id: "one_finger_per_fret",
+        name: "One Finger per Fret",
+        description: indoc! {"
+            Play the four frets in each string with one finger per fret, starting from the 6th
+            string and moving up to the 1st string.
+        "},
+        fingers: &[1, 2, 3, 4],
+        pick_strokes: "down, up, down, up",
+        remarks: None,
+    },
+    WarmupPattern {
+        id: "spider",
+        name: "Spider Exercise",
+        description: indoc! {"
+            Play the spider exercise across each pair of adjacent strings, starting with the 6th
+            and 5th strings. Fingers 1 and 3 play in the lower string, and fingers 2 and 4 play in
+            the higher string. Keep each finger down until it's needed again.
+        "},
+        fingers: &[1, 3, 2, 4],
+        pick_strokes: "down, up, down, up",
+        remarks: Some(
+            "Fingers 1 and 3 stay in the lower string of each pair, and fingers 2 and 4 in the \
+            higher one.",
+        ),
+    },
+    WarmupPattern {
+        id: "triplets",
+        name: "Triplet Patterns",
+        description: indoc! {"
+            Play the four frets in each string going up and back down without repeating the
+            highest note, so that each string is played as two groups of three notes. Start from
+            the 6th string and move up to the 1st string.
+        "},
+        fingers: &[1, 2, 3, 4, 3, 2],
+        pick_strokes: "down, up, down, up, down, up",
+        remarks: Some("Accent the first note of each group of three to feel the triplets."),
+    },
+];
+
+/// Returns the answer with the fingering of the pattern starting at the given fret.
+fn pattern_answer(pattern: &WarmupPattern, start_fret: u8) -> String {
+    let fingering = pattern
+        .fingers
+        .iter()
+        .map(|finger| format!("finger {} at fret {}", finger, start_fret + finger - 1))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let remarks = match pattern.remarks {
+        None => String::new(),
+        Some(remarks) => format!("\n{}\n", remarks),
+    };
+    formatdoc! {"
+        The fingering of the pattern is: {}.
+        {}
+        Use strict alternate picking with the pick strokes {}. Once the 1st string is
+        reached, play the pattern back down to the 6th string.
+    ", fingering, remarks, pattern.pick_strokes}
+}
+
+/// Generates the exercise builders for the lesson of the given pattern. There's one exercise for
+/// each starting fret.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    pattern: &'static WarmupPattern,
+) -> Vec<ExerciseBuilder> {
+    START_FRETS
+        .iter()
+        .map(|start_fret| {
+            let start_fret = *start_fret;
+            ExerciseBuilder {
+                directory_name: format!("fret_{}", start_fret),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            {}
+                            Start at fret {}.
+                        ", pattern.description, start_fret},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: pattern_answer(pattern, start_fret),
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::fret_{}", lesson_id, start_fret))
+                        .name(format!("{} from fret {}", pattern.name, start_fret))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let lesson_builders = PATTERNS
+        .iter()
+        .map(|pattern| {
+            let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, pattern.id));
+            LessonBuilder {
+                directory_name: format!("lesson_{}", pattern.id),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, pattern),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("Warmup: {}", pattern.name))
+                        .description(Some(format!(
+                            "Warm up the fingers with the {} pattern across all six strings.",
+                            pattern.name.to_lowercase()
+                        )))
+                        .dependencies(vec![])
+                        .metadata(Some(EXERCISE_METADATA.to_metadata()))
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "warmup".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Chromatic Warmups".to_string(),
+            dependencies: vec![],
+            description: Some(
+                "Warm up the fingers with chromatic patterns at the start of each session."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["technique"])
+    .with_technique_metadata(&["alternate picking"])
+    .with_standard_instruction_asset(indoc! {"
+        Start every practice session with these chromatic patterns to warm up both
+        hands. None of the lessons depend on other courses, so they are available from
+        the first session.
+
+        Play slowly and evenly with one finger per fret. Keep the fingers close to the
+        fretboard and only press as hard as needed for each note to sound clearly.
+    "})
+}
@@ -0,0 +1,9 @@
+pub mod basic_theory;
+pub mod capo_transposition;
+pub mod chord_tones_in_scale;
+pub mod fretboard_intervals;
+pub mod parallel_scales;
+pub mod pentatonic_blues_transition;
+pub mod relative_keys;
+pub mod scale_degrees;
+pub mod voice_leading;
@@ -0,0 +1,328 @@
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::{
+            notes::{Accidental, Note},
+            scales::ScaleType,
+        },
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    music::{
+        chromatic_note_name, note_from_semitones, note_from_semitones_flat, semitones,
+        NATURAL_NOTES,
+    },
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::basic");
+}
+
+/// The steps between consecutive notes of the major scale, in semitones.
+const MAJOR_SCALE_STEPS: [u8; 7] = [2, 2, 1, 2, 2, 2, 1];
+
+/// Returns the notes as a comma-separated list.
+fn notes_list(notes: &[Note]) -> String {
+    notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Returns an exercise builder for a flashcard with the given front and back.
+fn flashcard(
+    lesson_id: Ustr,
+    exercise_id: String,
+    name: String,
+    front: String,
+    back: String,
+) -> ExerciseBuilder {
+    ExerciseBuilder {
+        directory_name: exercise_id.clone(),
+        asset_builders: vec![
+            AssetBuilder {
+                file_name: "front.md".to_string(),
+                contents: front,
+            },
+            AssetBuilder {
+                file_name: "back.md".to_string(),
+                contents: back,
+            },
+        ],
+        manifest_closure: Box::new(move |m| {
+            #[allow(clippy::redundant_clone)]
+            m.clone()
+                .id(format!("{}::{}", lesson_id, exercise_id))
+                .name(name.clone())
+                .clone()
+        }),
+    }
+}
+
+/// Generates the exercises for the lesson on the chromatic scale. There's one exercise for each of
+/// the twelve notes in the octave.
+fn generate_chromatic_exercise_builders(lesson_id: Ustr) -> Vec<ExerciseBuilder> {
+    let chromatic_scale = (0..12)
+        .map(chromatic_note_name)
+        .collect::<Vec<String>>()
+        .join(", ");
+    (0..12)
+        .map(|pitch| {
+            let note_name = chromatic_note_name(pitch);
+            flashcard(
+                lesson_id,
+                format!("note_{}", note_from_semitones(pitch).to_ascii_string()),
+                format!("Name the note a half step above {}", note_name),
+                formatdoc! {"
+                    What note is a half step above {}?
+                ", note_name},
+                formatdoc! {"
+                    The note a half step above {} is {}.
+
+                    The chromatic scale contains the twelve notes in the octave, each one a half
+                    step apart: {}.
+                ", note_name, chromatic_note_name(pitch + 1), chromatic_scale},
+            )
+        })
+        .collect()
+}
+
+/// Generates the exercises for the lesson on the construction of the major scale. There's one
+/// exercise for each key.
+fn generate_major_scale_exercise_builders(lesson_id: Ustr) -> Result<Vec<ExerciseBuilder>> {
+    let mut builders = vec![];
+    for key in Note::all_keys(false) {
+        let notes = ScaleType::Major.notes(key)?.notes;
+        let steps = notes
+            .iter()
+            .zip(notes.iter().cycle().skip(1))
+            .zip(MAJOR_SCALE_STEPS)
+            .map(|((from, to), step)| {
+                format!(
+                    "- {} to {}: {} step",
+                    from.to_string(),
+                    to.to_string(),
+                    if step == 2 { "whole" } else { "half" }
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        builders.push(flashcard(
+            lesson_id,
+            format!("key_{}", key.to_ascii_string()),
+            format!("Build the {} major scale", key.to_string()),
+            formatdoc! {"
+                Build the {} major scale using the pattern W-W-H-W-W-W-H.
+            ", key.to_string()},
+            formatdoc! {"
+                The notes of the {} major scale are: {}.
+
+                {}
+            ", key.to_string(), notes_list(&notes), steps},
+        ));
+    }
+    Ok(builders)
+}
+
+/// Generates the exercises for the lesson on the concept of a key. There's one exercise for each
+/// key.
+fn generate_key_exercise_builders(lesson_id: Ustr) -> Result<Vec<ExerciseBuilder>> {
+    let mut builders = vec![];
+    for key in Note::all_keys(false) {
+        let notes = ScaleType::Major.notes(key)?.notes;
+        let accidentals = notes
+            .iter()
+            .filter(|note| note.1 != Accidental::Natural)
+            .copied()
+            .collect::<Vec<Note>>();
+        let signature = match accidentals.first() {
+            None => "no sharps or flats".to_string(),
+            Some(note) => format!(
+                "{} {}{}: {}",
+                accidentals.len(),
+                if note.1 == Accidental::Sharp {
+                    "sharp"
+                } else {
+                    "flat"
+                },
+                if accidentals.len() == 1 { "" } else { "s" },
+                notes_list(&accidentals)
+            ),
+        };
+        builders.push(flashcard(
+            lesson_id,
+            format!("key_{}", key.to_ascii_string()),
+            format!(
+                "Name the accidentals in the key of {} major",
+                key.to_string()
+            ),
+            formatdoc! {"
+                Which sharps or flats are found in the key of {} major?
+            ", key.to_string()},
+            formatdoc! {"
+                The key of {} major has {}.
+
+                Its notes are: {}.
+            ", key.to_string(), signature, notes_list(&notes)},
+        ));
+    }
+    Ok(builders)
+}
+
+/// Generates the exercises for the lesson on sharps and flats. There's one exercise for each
+/// natural note.
+fn generate_accidental_exercise_builders(lesson_id: Ustr) -> Vec<ExerciseBuilder> {
+    NATURAL_NOTES
+        .iter()
+        .map(|natural_note| {
+            let natural = Note(*natural_note, Accidental::Natural);
+            let sharp = Note(*natural_note, Accidental::Sharp);
+            let flat = Note(*natural_note, Accidental::Flat);
+            flashcard(
+                lesson_id,
+                format!("note_{}", natural.to_ascii_string()),
+                format!(
+                    "Name the notes {} and {}",
+                    sharp.to_string(),
+                    flat.to_string()
+                ),
+                formatdoc! {"
+                    Which notes are {} and {}? By which other names are they known?
+                ", sharp.to_string(), flat.to_string()},
+                formatdoc! {"
+                    {} is a half step above {}. It's the same note as {}.
+
+                    {} is a half step below {}. It's the same note as {}.
+                ",
+                sharp.to_string(),
+                natural.to_string(),
+                note_from_semitones_flat(semitones(sharp)).to_string(),
+                flat.to_string(),
+                natural.to_string(),
+                note_from_semitones(semitones(flat)).to_string()},
+            )
+        })
+        .collect()
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let lesson_id = |name: &str| Ustr::from(&format!("{}::{}", course_id, name));
+    let lessons = vec![
+        (
+            "chromatic_scale",
+            "The Chromatic Scale",
+            "Learn the twelve notes in the octave and the half steps between them.",
+            generate_chromatic_exercise_builders(lesson_id("chromatic_scale")),
+        ),
+        (
+            "major_scale",
+            "Building the Major Scale",
+            "Build the major scale in every key with the pattern of whole and half steps.",
+            generate_major_scale_exercise_builders(lesson_id("major_scale"))?,
+        ),
+        (
+            "keys",
+            "Keys",
+            "Learn which sharps or flats belong to each major key.",
+            generate_key_exercise_builders(lesson_id("keys"))?,
+        ),
+        (
+            "accidentals",
+            "Sharps and Flats",
+            "Learn how sharps and flats change a note and the other names of each note.",
+            generate_accidental_exercise_builders(lesson_id("accidentals")),
+        ),
+    ];
+
+    let mut previous_lesson: Option<Ustr> = None;
+    let mut lesson_builders = vec![];
+    for (name, lesson_name, description, exercise_builders) in lessons {
+        let lesson_id = lesson_id(name);
+        let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+        previous_lesson = Some(lesson_id);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", name),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Declarative)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(lesson_name.to_string())
+                    .description(Some(description.to_string()))
+                    .dependencies(dependencies.clone())
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "basic_theory".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Basic Music Theory".to_string(),
+            dependencies: vec![],
+            description: Some(
+                "Learn the basic music theory needed to make sense of the fretboard courses."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["scales", "keys"])
+    .with_standard_instruction_asset(indoc! {"
+        This course covers the music theory used by the rest of the courses. No guitar
+        is needed to complete it.
+
+        Music is built from twelve notes, each a half step apart. Scales select some of
+        those notes following a pattern of steps, and the key of a song tells you which
+        scale its notes come from. Sharps and flats raise or lower a note by a half
+        step.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::basic_theory::course_builder};
+
+    /// Verifies that the course contains a lesson for each topic.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 4);
+        assert_eq!(course.exercise_count(), 43);
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt, constants::AUTHORS, fretboard::basic_guitar_fretboard,
+    music::semitones_up,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::capo_transposition");
+}
+
+/// The open major chord shapes used with the capo.
+const OPEN_SHAPES: [Note; 5] = [Note::C, Note::A, Note::G, Note::E, Note::D];
+
+/// The highest fret at which the capo is placed in the exercises. Higher positions are rarely
+/// used because the shapes become too cramped.
+const MAX_CAPO_FRET: u8 = 9;
+
+/// Generates the exercise builders for the lesson of the given key. There's one exercise for each
+/// open shape that can play in the key with the capo between the first fret and `MAX_CAPO_FRET`.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Vec<ExerciseBuilder> {
+    OPEN_SHAPES
+        .iter()
+        .filter_map(|shape| {
+            let shape = *shape;
+            let capo_fret = semitones_up(shape, key);
+            if capo_fret == 0 || capo_fret > MAX_CAPO_FRET {
+                return None;
+            }
+
+            Some(ExerciseBuilder {
+                directory_name: format!("{}_shape", shape.to_ascii_string()),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Which capo fret and which open chord shape produce the key of {}
+                            Major? Use the open {} Major shape.
+                        ", key.to_string(), shape.to_string()},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            Capo {}, {} shape → {} Major.
+
+                            Each fret raises the open shape by one half step, and {} is {}
+                            half steps above {}.
+                        ",
+                        capo_fret,
+                        shape.to_string(),
+                        key.to_string(),
+                        key.to_string(),
+                        capo_fret,
+                        shape.to_string()},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}_shape", lesson_id, shape.to_string()))
+                        .name(format!(
+                            "Play in {} Major with the {} shape",
+                            key.to_string(),
+                            shape.to_string()
+                        ))
+                        .clone()
+                }),
+            })
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let course_id = *COURSE_ID;
+    let lesson_builders = Note::all_keys(false)
+        .into_iter()
+        .map(|key| {
+            let lesson_id = Ustr::from(&format!("{}::{}", course_id, key.to_string()));
+            let dependencies = key
+                .previous_key_in_circle()
+                .map(|previous_key| {
+                    Ustr::from(&format!("{}::{}", course_id, previous_key.to_string()))
+                })
+                .into_iter()
+                .collect::<Vec<Ustr>>();
+
+            LessonBuilder {
+                directory_name: format!("lesson_{}", key.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, key),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("Capo Transposition to {} Major", key.to_string()))
+                        .description(Some(format!(
+                            "Find the capo positions and open shapes that play in {} major.",
+                            key.to_string()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .metadata(Some(BTreeMap::from([(
+                            MusicMetadata::Key.to_string(),
+                            vec![key.to_ascii_string()],
+                        )])))
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "capo_transposition".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Capo Transposition".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Learn to play in every key by combining the capo with open chord shapes."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_standard_instruction_asset(indoc! {"
+        A capo raises the pitch of every open string by one half step for each fret.
+        Chord shapes played behind the capo keep their names relative to the capo, but
+        sound higher by the same number of half steps.
+
+        For each exercise, find the fret, place the capo, and play the open shape to
+        confirm that it sounds in the given key.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::capo_transposition::course_builder};
+
+    /// Verifies that the course contains a lesson for each key, with an exercise for each open
+    /// shape that can reach it.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 45);
+        Ok(())
+    }
+}
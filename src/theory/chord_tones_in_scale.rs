@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::major_scale,
+    music::{diatonic_triad, semitones_up},
+    theory::scale_degrees,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::chord_tones_in_scale");
+}
+
+/// The names of the tones of a seventh chord, from the root to the seventh.
+const CHORD_TONE_NAMES: [&str; 4] = ["Root", "3rd", "5th", "7th"];
+
+/// Returns the ID of the lesson for the given key.
+fn lesson_id(key: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()))
+}
+
+/// Returns the suffix of the seventh chord with the given notes, found from the intervals between
+/// consecutive chord tones.
+fn seventh_chord_suffix(chord_tones: &[Note]) -> Result<&'static str> {
+    let intervals = chord_tones
+        .windows(2)
+        .map(|pair| semitones_up(pair[0], pair[1]))
+        .collect::<Vec<u8>>();
+    match intervals.as_slice() {
+        [4, 3, 4] => Ok("maj7"),
+        [3, 4, 3] => Ok("m7"),
+        [4, 3, 3] => Ok("7"),
+        [3, 3, 4] => Ok("m7♭5"),
+        _ => Err(anyhow!("cannot determine the quality of the seventh chord")),
+    }
+}
+
+/// Generates the exercise builders for the lesson in the given key. There's one exercise for each
+/// degree of the scale, which uses the seventh chord built on that degree as the tonal center.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let mut builders = vec![];
+    for degree in 1..=7 {
+        let chord_degrees = (0..4)
+            .map(|tone| (degree - 1 + 2 * tone) % 7 + 1)
+            .collect::<Vec<usize>>();
+        let chord_tones = chord_degrees
+            .iter()
+            .map(|chord_degree| scale_notes[chord_degree - 1])
+            .collect::<Vec<Note>>();
+        let chord_name = format!(
+            "{}{}",
+            chord_tones[0].to_string(),
+            seventh_chord_suffix(&chord_tones)?
+        );
+        let roman_numeral = diatonic_triad(key, degree)?.roman_numeral();
+        let chord_tones_list = CHORD_TONE_NAMES
+            .iter()
+            .zip(chord_tones.iter().zip(chord_degrees.iter()))
+            .map(|(tone_name, (note, chord_degree))| {
+                format!(
+                    "- {}: {} (degree {} of the scale)",
+                    tone_name,
+                    note.to_string(),
+                    chord_degree
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        builders.push(ExerciseBuilder {
+            directory_name: format!("degree_{}", degree),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Key: {} Major
+
+                        Chord: {} ({})
+
+                        Play through the {} major scale starting from {}. Emphasize the chord
+                        tones of {} by landing on them on the strong beats.
+                    ",
+                    key.to_string(),
+                    chord_name,
+                    roman_numeral,
+                    key.to_string(),
+                    chord_tones[0].to_string(),
+                    chord_name},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The chord tones of {} are:
+
+                        {}
+
+                        The rest of the notes of the scale connect the chord tones.
+                    ", chord_name, chord_tones_list},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::degree_{}", lesson_id, degree))
+                    .name(format!(
+                        "Emphasize the chord tones of {} in {} Major",
+                        chord_name,
+                        key.to_string()
+                    ))
+                    .clone()
+            }),
+        });
+    }
+    Ok(builders)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        let dependencies = key
+            .previous_key_in_circle()
+            .map(lesson_id)
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = lesson_id(key);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!("Chord Tones in {} Major", key.to_string()))
+                    .description(Some(format!(
+                        "Find the chord tones of each diatonic chord in the {} major scale.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![key.to_ascii_string()],
+                    )])))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "chord_tones_in_scale".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Chord Tones in the Major Scale".to_string(),
+            dependencies: vec![*scale_degrees::COURSE_ID, *major_scale::COURSE_ID],
+            description: Some(
+                "Emphasize the chord tones of each diatonic chord while playing the major scale."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony", "improvisation"])
+    .with_concept_metadata(&["chords", "scales"])
+    .with_scale_metadata(ScaleType::Major)
+    .with_standard_instruction_asset(indoc! {"
+        Each degree of the major scale is the root of a diatonic seventh chord, built by
+        stacking every other note of the scale. The root, 3rd, 5th, and 7th of the chord
+        are its chord tones.
+
+        Play the scale slowly and place the chord tones on the strong beats, so that the
+        chord can be heard in the line even without an accompaniment. Listen to how the
+        same notes sound different depending on which chord is the center.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::chord_tones_in_scale::course_builder};
+
+    /// Verifies that the course contains a lesson for each key, with an exercise for each degree of
+    /// the scale.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 84);
+        Ok(())
+    }
+}
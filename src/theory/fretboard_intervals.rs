@@ -0,0 +1,187 @@
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder},
+    data::{
+        music::intervals::Interval, CourseManifest, ExerciseAsset, ExerciseManifestBuilder,
+        ExerciseType, LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::basic_guitar_fretboard,
+    music::{interval_semitones, note_from_semitones, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::fretboard_intervals");
+}
+
+/// The intervals taught in this course, in the order in which they are introduced, along with the
+/// IDs used in the lesson IDs and directory names.
+const INTERVALS: [(Interval, &str); 12] = [
+    (Interval::MinorSecond, "minor_second"),
+    (Interval::MajorSecond, "major_second"),
+    (Interval::MinorThird, "minor_third"),
+    (Interval::MajorThird, "major_third"),
+    (Interval::PerfectFourth, "perfect_fourth"),
+    (Interval::Tritone, "tritone"),
+    (Interval::PerfectFifth, "perfect_fifth"),
+    (Interval::MinorSixth, "minor_sixth"),
+    (Interval::MajorSixth, "major_sixth"),
+    (Interval::MinorSeventh, "minor_seventh"),
+    (Interval::MajorSeventh, "major_seventh"),
+    (Interval::Octave, "octave"),
+];
+
+/// The fret of the starting note in the lower string of each pair.
+const START_FRET: u8 = 5;
+
+/// Generates the exercise builders for the lesson of the given interval. There's one exercise for
+/// each pair of adjacent strings, with the starting note in the lower string and the target note in
+/// the higher string.
+fn generate_exercise_builders(lesson_id: Ustr, interval: Interval) -> Vec<ExerciseBuilder> {
+    tunings::STANDARD
+        .windows(2)
+        .map(|strings| {
+            let (low_number, low_pitch) = (strings[0].string_number, strings[0].pitch());
+            let (high_number, high_pitch) = (strings[1].string_number, strings[1].pitch());
+            let start_note = note_from_semitones(semitones(strings[0].open_note) + START_FRET);
+            let target_pitch = low_pitch + START_FRET + interval_semitones(interval);
+            let target_fret = target_pitch - high_pitch;
+            let target_note = note_from_semitones(target_pitch);
+
+            ExerciseBuilder {
+                directory_name: format!("strings_{}_and_{}", low_number, high_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Where is the interval of {} above the note {} on string {} (fret {})?
+                            Find it in string {}.
+                        ",
+                        interval.to_string(),
+                        start_note.to_string(),
+                        low_number,
+                        START_FRET,
+                        high_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The target note is {}, on string {}, fret {}.
+                        ", target_note.to_string(), high_number, target_fret},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!(
+                            "{}::strings_{}_and_{}",
+                            lesson_id, low_number, high_number
+                        ))
+                        .name(format!(
+                            "Find the {} from string {} to string {}",
+                            interval.to_string(),
+                            low_number,
+                            high_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn course_builder() -> CourseBuilder {
+    let mut previous_lesson: Option<Ustr> = None;
+    let lesson_builders = INTERVALS
+        .iter()
+        .map(|(interval, id)| {
+            let interval = *interval;
+            let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, id));
+            let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+            previous_lesson = Some(lesson_id);
+
+            LessonBuilder {
+                directory_name: format!("lesson_{}", id),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(*COURSE_ID)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(lesson_id, interval),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name(format!("The {} in the Fretboard", interval.to_string()))
+                        .description(Some(format!(
+                            "Find the {} above a note across each pair of adjacent strings.",
+                            interval.to_string().to_lowercase()
+                        )))
+                        .dependencies(dependencies.clone())
+                        .clone()
+                }),
+            }
+        })
+        .collect();
+
+    CourseBuilder {
+        directory_name: "fretboard_intervals".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Intervals in the Fretboard".to_string(),
+            dependencies: vec![*basic_guitar_fretboard::COURSE_ID],
+            description: Some(
+                "Learn to find every interval from a note across the strings of the guitar."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_fretboard_skill_metadata()
+    .with_concept_metadata(&["intervals"])
+    .with_standard_instruction_asset(indoc! {"
+        Each interval forms the same shape between two adjacent strings no matter the
+        starting note, except between the 3rd and 2nd strings. Since the B string is
+        tuned a major third above the G string instead of a perfect fourth, the shape
+        moves one fret higher when crossing them.
+
+        Play both notes and listen to the sound of the interval before flipping the
+        card.
+    "})
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::fretboard_intervals::course_builder};
+
+    /// Verifies that the course contains a lesson for each interval, with five exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder())?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 60);
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, minor_scale},
+    music::semitones_up,
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::parallel_scales");
+}
+
+/// The roots of the scales in the order in which they are introduced. The roots follow the circle
+/// of fifths, but C♯ and F♯ are used instead of D♭ and G♭ because the minor scales of the latter
+/// require double flats.
+const ROOTS: [Note; 12] = [
+    Note::C,
+    Note::G,
+    Note::D,
+    Note::A,
+    Note::E,
+    Note::B,
+    Note::F_SHARP,
+    Note::C_SHARP,
+    Note::F,
+    Note::B_FLAT,
+    Note::E_FLAT,
+    Note::A_FLAT,
+];
+
+/// Returns the list of the degrees that differ between the major and minor scales of the same root,
+/// along with the frets of both notes in the given string.
+fn differences_answer(open_note: Note, major_notes: &[Note], minor_notes: &[Note]) -> String {
+    major_notes
+        .iter()
+        .zip(minor_notes.iter())
+        .enumerate()
+        .filter(|(_, (major_note, minor_note))| major_note != minor_note)
+        .map(|(index, (major_note, minor_note))| {
+            format!(
+                "- Degree {}: {} (fret {}) in major becomes {} (fret {}) in minor, {} semitone \
+                lower.",
+                index + 1,
+                major_note.to_string(),
+                semitones_up(open_note, *major_note),
+                minor_note.to_string(),
+                semitones_up(open_note, *minor_note),
+                semitones_up(*minor_note, *major_note)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Generates the exercise builders for the lesson with the given root. There's one exercise for
+/// each string.
+fn generate_exercise_builders(lesson_id: Ustr, root: Note) -> Result<Vec<ExerciseBuilder>> {
+    let major_notes = ScaleType::Major.notes(root)?.notes;
+    let minor_notes = ScaleType::Minor.notes(root)?.notes;
+    Ok(tunings::STANDARD
+        .iter()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let open_note = guitar_string.open_note;
+            ExerciseBuilder {
+                directory_name: format!("string_{}", string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} Major scale and then the {} Minor scale in string {}.
+                            Which notes change between the two scales?
+                        ", root.to_string(), root.to_string(), string_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The following degrees change from the major to the minor scale:
+
+                            {}
+
+                            The rest of the notes are the same in both scales.
+                        ", differences_answer(open_note, &major_notes, &minor_notes)},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::string_{}", lesson_id, string_number))
+                        .name(format!(
+                            "Compare {} Major and {} Minor in string {}",
+                            root.to_string(),
+                            root.to_string(),
+                            string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let mut previous_lesson: Option<Ustr> = None;
+    let mut lesson_builders = vec![];
+    for root in ROOTS {
+        let lesson_id = Ustr::from(&format!("{}::{}", course_id, root.to_string()));
+        let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+        previous_lesson = Some(lesson_id);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", root.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(course_id)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, root)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "{} Major and {} Minor",
+                        root.to_string(),
+                        root.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Compare the notes of the {} major and {} minor scales.",
+                        root.to_string(),
+                        root.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(BTreeMap::from([(
+                        MusicMetadata::Key.to_string(),
+                        vec![root.to_ascii_string()],
+                    )])))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "parallel_scales".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Parallel Major and Minor Scales".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *minor_scale::COURSE_ID],
+            description: Some(
+                "Compare the major and minor scales which share the same root.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["scales"])
+    .with_standard_instruction_asset(indoc! {"
+        Parallel scales share the same root but not the same notes. The natural minor
+        scale is obtained by lowering the 3rd, 6th, and 7th degrees of the major scale
+        by one semitone.
+
+        Play both scales in the same string, one after the other, and listen to how
+        the lowered degrees change the character of the scale.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::parallel_scales::course_builder};
+
+    /// Verifies that the course contains a lesson for each key, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 72);
+        Ok(())
+    }
+}
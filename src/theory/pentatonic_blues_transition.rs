@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::minor_pentatonic_scale,
+    metadata::Genre,
+    music::{note_from_semitones_flat, note_to_fret, semitones},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::pentatonic_blues");
+}
+
+/// Returns the ID of the lesson for the given minor key.
+fn lesson_id(key: Note) -> Ustr {
+    Ustr::from(&format!("{}::{}", *COURSE_ID, key.to_string()))
+}
+
+/// Returns the frets of the note in the given string as a comma-separated list.
+fn frets(open_note: Note, note: Note) -> String {
+    note_to_fret(open_note, note)
+        .iter()
+        .map(|fret| fret.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Generates the exercise builders for the lesson of the given minor key. There's one exercise for
+/// each string.
+fn generate_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let pentatonic_notes = ScaleType::MinorPentatonic.notes(key)?.notes;
+    let fourth = pentatonic_notes[2];
+    let fifth = pentatonic_notes[3];
+    // The blue note is spelled as the flat fifth. Flats are used for the spelling since some keys
+    // would otherwise require double flats.
+    let blue_note = note_from_semitones_flat(semitones(fifth) + 11);
+    let pentatonic_list = pentatonic_notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    Ok(tunings::STANDARD
+        .iter()
+        .map(|guitar_string| {
+            let string_number = guitar_string.string_number;
+            let open_note = guitar_string.open_note;
+            ExerciseBuilder {
+                directory_name: format!("string_{}", string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} minor pentatonic scale in string {} ({}). Weave in and
+                            out of the blue note while improvising, moving back to the
+                            pentatonic notes after each time it's played.
+                        ", key.to_string(), string_number, open_note.to_string()},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The notes of the {} minor pentatonic scale are: {}.
+
+                            The blue note is {}, the flat fifth of the key. It's found in frets {}
+                            of string {}, between {} (frets {}) and {} (frets {}).
+
+                            Try resolving it in either direction:
+
+                            - {} → {} → {}
+                            - {} → {} → {} → {}
+                        ",
+                        key.to_string(),
+                        pentatonic_list,
+                        blue_note.to_string(),
+                        frets(open_note, blue_note),
+                        string_number,
+                        fourth.to_string(),
+                        frets(open_note, fourth),
+                        fifth.to_string(),
+                        frets(open_note, fifth),
+                        fourth.to_string(),
+                        blue_note.to_string(),
+                        fifth.to_string(),
+                        fifth.to_string(),
+                        blue_note.to_string(),
+                        fourth.to_string(),
+                        key.to_string()},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::string_{}", lesson_id, string_number))
+                        .name(format!(
+                            "Add the blue note to {} minor pentatonic in string {}",
+                            key.to_string(),
+                            string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for major_key in Note::all_keys(false) {
+        let key = major_key.relative_minor()?;
+        let dependencies = major_key
+            .previous_key_in_circle()
+            .map(|previous_key| previous_key.relative_minor())
+            .transpose()?
+            .map(lesson_id)
+            .into_iter()
+            .collect::<Vec<Ustr>>();
+        let lesson_id = lesson_id(key);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", key.to_ascii_string()),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(format!(
+                        "From {} Minor Pentatonic to the Blues Scale",
+                        key.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Move between the {} minor pentatonic scale and its blue note.",
+                        key.to_string()
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(BTreeMap::from([
+                        (MusicMetadata::Key.to_string(), vec![key.to_ascii_string()]),
+                        (
+                            MusicMetadata::ScaleType.to_string(),
+                            vec!["minor pentatonic".to_string()],
+                        ),
+                    ])))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "pentatonic_blues_transition".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "From the Minor Pentatonic to the Blues Scale".to_string(),
+            dependencies: vec![*minor_pentatonic_scale::COURSE_ID],
+            description: Some(
+                "Learn to move between the minor pentatonic scale and the blue note in all keys."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["improvisation"])
+    .with_concept_metadata(&["scales"])
+    .with_genre_metadata(&[Genre::Blues])
+    .with_standard_instruction_asset(indoc! {"
+        The blues scale adds a single note to the minor pentatonic scale: the flat
+        fifth, also called the blue note. It's a passing tone, so it sounds best when
+        it quickly resolves to the fourth or the fifth of the key.
+
+        Start by improvising with the pentatonic notes only and add the blue note
+        little by little. Avoid landing on it at the end of a phrase.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        in_memory::build_course_in_memory, theory::pentatonic_blues_transition::course_builder,
+    };
+
+    /// Verifies that the course contains a lesson for each key, with six exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 12);
+        assert_eq!(course.exercise_count(), 72);
+        Ok(())
+    }
+}
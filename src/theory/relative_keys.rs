@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::{major_scale, minor_scale},
+    music::semitones_up,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::relative_keys");
+}
+
+/// The strings from which the scales are played, given as their number and open note. Only the three
+/// lowest strings are used so that there's room to play the scales upwards.
+const ROOT_STRINGS: [(usize, Note); 3] = [(6, Note::E), (5, Note::A), (4, Note::D)];
+
+/// Returns the ID of the lesson for the given key and scale.
+fn lesson_id(key: Note, scale_id: &str) -> Ustr {
+    Ustr::from(&format!(
+        "{}::{}::{}",
+        *COURSE_ID,
+        key.to_string(),
+        scale_id
+    ))
+}
+
+/// Returns the notes of the scale as a comma-separated list.
+fn notes_list(notes: &[Note]) -> String {
+    notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Generates the exercise builders for the lesson with the major scale of the given key. There's
+/// one exercise for each root string.
+fn generate_major_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let major_notes = ScaleType::Major.notes(key)?.notes;
+    Ok(ROOT_STRINGS
+        .iter()
+        .map(|(string_number, open_note)| {
+            let string_number = *string_number;
+            let fret = semitones_up(*open_note, key);
+            ExerciseBuilder {
+                directory_name: format!("string_{}", string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            Play the {} Major scale starting from its root on string {}.
+                        ", key.to_string(), string_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The root is in fret {} of string {}.
+
+                            The notes of the {} Major scale are: {}.
+                        ", fret, string_number, key.to_string(), notes_list(&major_notes)},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::string_{}", lesson_id, string_number))
+                        .name(format!(
+                            "Play the {} Major scale from string {}",
+                            key.to_string(),
+                            string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+/// Generates the exercise builders for the lesson with the relative minor scale of the given major
+/// key. There's one exercise for each root string.
+fn generate_minor_exercise_builders(lesson_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let minor_key = key.relative_minor()?;
+    let major_notes = ScaleType::Major.notes(key)?.notes;
+    let minor_notes = ScaleType::Minor.notes(minor_key)?.notes;
+    Ok(ROOT_STRINGS
+        .iter()
+        .map(|(string_number, open_note)| {
+            let string_number = *string_number;
+            let major_fret = semitones_up(*open_note, key);
+            let minor_fret = semitones_up(*open_note, minor_key);
+            ExerciseBuilder {
+                directory_name: format!("string_{}", string_number),
+                asset_builders: vec![
+                    AssetBuilder {
+                        file_name: "front.md".to_string(),
+                        contents: formatdoc! {"
+                            What is the root of the relative minor of {} Major?
+
+                            Play the {} Major scale from fret {} of string {}, then play its
+                            relative minor scale starting from its root on the same string.
+                        ", key.to_string(), key.to_string(), major_fret, string_number},
+                    },
+                    AssetBuilder {
+                        file_name: "back.md".to_string(),
+                        contents: formatdoc! {"
+                            The relative minor of {} Major is {} Minor. Its root is the sixth
+                            degree of the major scale, in fret {} of string {}.
+
+                            Both scales contain the same notes:
+
+                            - {} Major: {}.
+                            - {} Minor: {}.
+                        ",
+                        key.to_string(),
+                        minor_key.to_string(),
+                        minor_fret,
+                        string_number,
+                        key.to_string(),
+                        notes_list(&major_notes),
+                        minor_key.to_string(),
+                        notes_list(&minor_notes)},
+                    },
+                ],
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::string_{}", lesson_id, string_number))
+                        .name(format!(
+                            "Play the relative minor of {} Major from string {}",
+                            key.to_string(),
+                            string_number
+                        ))
+                        .clone()
+                }),
+            }
+        })
+        .collect())
+}
+
+/// Generates a pair of lessons for the given key. The first lesson plays the major scale and the
+/// second plays its relative minor. The major lesson depends on the relative minor lesson of the
+/// previous key in the circle of fifths.
+fn generate_lesson_builders(key: Note) -> Result<Vec<LessonBuilder>> {
+    let course_id = *COURSE_ID;
+    let minor_key = key.relative_minor()?;
+    let major_lesson_id = lesson_id(key, "major");
+    let minor_lesson_id = lesson_id(key, "relative_minor");
+    let major_dependencies = key
+        .previous_key_in_circle()
+        .map(|previous_key| lesson_id(previous_key, "relative_minor"))
+        .into_iter()
+        .collect::<Vec<Ustr>>();
+    let exercise_manifest_template = |lesson_id: Ustr| {
+        ExerciseManifestBuilder::default()
+            .course_id(course_id)
+            .lesson_id(lesson_id)
+            .exercise_type(ExerciseType::Procedural)
+            .exercise_asset(ExerciseAsset::FlashcardAsset {
+                front_path: "front.md".to_string(),
+                back_path: Some("back.md".to_string()),
+            })
+            .clone()
+    };
+
+    Ok(vec![
+        LessonBuilder {
+            directory_name: format!("lesson_{}_major", key.to_ascii_string()),
+            exercise_manifest_template: exercise_manifest_template(major_lesson_id),
+            asset_builders: vec![],
+            exercise_builders: generate_major_exercise_builders(major_lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(major_lesson_id)
+                    .name(format!("The {} Major Scale", key.to_string()))
+                    .description(Some(format!(
+                        "Play the {} major scale before finding its relative minor.",
+                        key.to_string()
+                    )))
+                    .dependencies(major_dependencies.clone())
+                    .metadata(Some(BTreeMap::from([
+                        (MusicMetadata::Key.to_string(), vec![key.to_ascii_string()]),
+                        (
+                            MusicMetadata::ScaleType.to_string(),
+                            vec!["major".to_string()],
+                        ),
+                    ])))
+                    .clone()
+            }),
+        },
+        LessonBuilder {
+            directory_name: format!("lesson_{}_relative_minor", key.to_ascii_string()),
+            exercise_manifest_template: exercise_manifest_template(minor_lesson_id),
+            asset_builders: vec![],
+            exercise_builders: generate_minor_exercise_builders(minor_lesson_id, key)?,
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(minor_lesson_id)
+                    .name(format!(
+                        "{} Minor, the Relative Minor of {} Major",
+                        minor_key.to_string(),
+                        key.to_string()
+                    ))
+                    .description(Some(format!(
+                        "Play {} minor right after {} major and compare their notes.",
+                        minor_key.to_string(),
+                        key.to_string()
+                    )))
+                    .dependencies(vec![major_lesson_id])
+                    .metadata(Some(BTreeMap::from([
+                        (
+                            MusicMetadata::Key.to_string(),
+                            vec![minor_key.to_ascii_string()],
+                        ),
+                        (
+                            MusicMetadata::ScaleType.to_string(),
+                            vec!["minor".to_string()],
+                        ),
+                    ])))
+                    .clone()
+            }),
+        },
+    ])
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut lesson_builders = vec![];
+    for key in Note::all_keys(false) {
+        lesson_builders.extend(generate_lesson_builders(key)?);
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "relative_keys".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Relative Major and Minor Keys".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID, *minor_scale::COURSE_ID],
+            description: Some(
+                "Learn that every major scale shares its notes with its relative minor scale."
+                    .to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["harmony"])
+    .with_concept_metadata(&["keys"])
+    .with_standard_instruction_asset(indoc! {"
+        Every major key has a relative minor key which uses the same notes. The root
+        of the relative minor is the sixth degree of the major scale, which is found
+        three frets below the root of the major scale in the same string, or nine frets
+        above it when the root of the major scale is too close to the nut.
+
+        Play the major scale first and then the relative minor, and listen to how the
+        same notes sound different when a different note is heard as the root.
+    "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::relative_keys::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key and its relative minor, with
+    /// three exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 24);
+        assert_eq!(course.exercise_count(), 72);
+        Ok(())
+    }
+}
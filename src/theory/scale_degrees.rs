@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
+        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt, constants::AUTHORS, fretboard::major_scale,
+    music::diatonic_triad,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::scale_degrees");
+}
+
+/// Generates the exercise builders for the lesson in the given key. There's one exercise for each
+/// note of the scale.
+fn generate_exercise_builders(course_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let scale_notes = ScaleType::Major.notes(key)?.notes;
+    let mut builders = vec![];
+    for (index, note) in scale_notes.into_iter().enumerate() {
+        let degree = index + 1;
+        let roman_numeral = diatonic_triad(key, degree)?.roman_numeral();
+        builders.push(ExerciseBuilder {
+            directory_name: format!("note_{}", note.to_ascii_string()),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Key: {} Major
+
+                        Note: {}
+
+                        Which degree of the scale does the note occupy?
+                    ", key.to_string(), note.to_string()},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        {} is degree {} ({}) of the {} Major scale.
+                    ", note.to_string(), degree, roman_numeral, key.to_string()},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!(
+                        "{}::{}::{}",
+                        course_id,
+                        key.to_string(),
+                        note.to_string()
+                    ))
+                    .name(format!(
+                        "Identify the degree of {} in {} Major",
+                        note.to_string(),
+                        key.to_string()
+                    ))
+                    .clone()
+            }),
+        });
+    }
+    Ok(builders)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let course_generator = CircleFifthsCourse {
+        directory_name: "scale_degrees".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Scale Degrees".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID],
+            description: Some(
+                "Identify the degree of each note of the major scale in all keys.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        course_asset_builders: vec![],
+        note_alias: None,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+        lesson_builder_generator: Box::new(move |note, previous_note| {
+            let lesson_id = format!("{}::{}", course_id, note.to_string());
+
+            Ok(LessonBuilder {
+                directory_name: format!("lesson_{}", note.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(course_id, note)?,
+                manifest_closure: Box::new(move |m| {
+                    let deps = match previous_note {
+                        None => vec![],
+                        Some(previous_note) => vec![Ustr::from(&format!(
+                            "{}::{}",
+                            course_id,
+                            previous_note.to_string()
+                        ))],
+                    };
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", course_id, note.to_string()))
+                        .name(format!("Scale Degrees in {} Major", note.to_string()))
+                        .description(Some(format!(
+                            "Identify the scale degrees of the notes in the key of {} major.",
+                            note.to_string()
+                        )))
+                        .dependencies(deps)
+                        .metadata(Some(BTreeMap::from([(
+                            MusicMetadata::Key.to_string(),
+                            vec![note.to_ascii_string()],
+                        )])))
+                        .clone()
+                }),
+            })
+        }),
+        extra_lessons_generator: None,
+    };
+    Ok(course_generator
+        .generate_course_builder()?
+        .with_instrument_metadata()
+        .with_skill_metadata(&["harmony"])
+        .with_scale_metadata(ScaleType::Major)
+        .with_standard_instruction_asset(indoc! {"
+            Each note of a key can be named by its position in the major scale, from 1 for
+            the tonic to 7 for the leading tone. The Roman numeral refers to the triad built
+            on that degree, in uppercase for major triads, lowercase for minor triads, and
+            with a ° for the diminished triad.
+
+            Thinking in scale degrees makes it easier to transpose melodies and chord
+            progressions to any key.
+        "}))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::scale_degrees::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key, with an exercise for each
+    /// degree of the scale.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 105);
+        Ok(())
+    }
+}
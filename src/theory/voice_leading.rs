@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::{circle_fifths::CircleFifthsCourse, MusicMetadata},
+        AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::notes::Note, CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::major_scale,
+    instructions,
+    metadata::Genre,
+    music::{diatonic_triad, shortest_motion, Triad},
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::theory::voice_leading");
+}
+
+/// The pairs of scale degrees whose chords are connected in each lesson. Each pair is a common
+/// movement between two diatonic chords.
+const CHORD_PAIRS: [(usize, usize); 8] = [
+    (1, 4),
+    (4, 5),
+    (5, 1),
+    (1, 6),
+    (6, 2),
+    (2, 5),
+    (6, 4),
+    (3, 6),
+];
+
+/// Returns a description of the motion of a single voice.
+fn describe_motion(from: Note, to: Note) -> String {
+    match shortest_motion(from, to) {
+        0 => format!("{} is held as a common note", from.to_string()),
+        1 => format!(
+            "{} moves a half step up to {}",
+            from.to_string(),
+            to.to_string()
+        ),
+        -1 => format!(
+            "{} moves a half step down to {}",
+            from.to_string(),
+            to.to_string()
+        ),
+        2 => format!(
+            "{} moves a whole step up to {}",
+            from.to_string(),
+            to.to_string()
+        ),
+        -2 => format!(
+            "{} moves a whole step down to {}",
+            from.to_string(),
+            to.to_string()
+        ),
+        motion => format!(
+            "{} moves {} semitones {} to {}",
+            from.to_string(),
+            motion.abs(),
+            if motion > 0 { "up" } else { "down" },
+            to.to_string()
+        ),
+    }
+}
+
+/// Returns the given notes as a comma-separated list.
+fn join_notes(notes: &[Note]) -> String {
+    notes
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Returns the notes of the second chord in the order which moves each note of the first chord
+/// by the smallest total distance.
+fn smoothest_voicing(from: &Triad, to: &Triad) -> Vec<Note> {
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let best = permutations
+        .iter()
+        .min_by_key(|permutation| {
+            permutation
+                .iter()
+                .enumerate()
+                .map(|(voice, index)| shortest_motion(from.notes[voice], to.notes[*index]).abs())
+                .sum::<i8>()
+        })
+        .unwrap();
+    best.iter().map(|index| to.notes[*index]).collect()
+}
+
+/// Generates the exercise builders for the lesson in the given key.
+fn generate_exercise_builders(course_id: Ustr, key: Note) -> Result<Vec<ExerciseBuilder>> {
+    let mut builders = vec![];
+    for (from_degree, to_degree) in CHORD_PAIRS {
+        let from = diatonic_triad(key, from_degree)?;
+        let to = diatonic_triad(key, to_degree)?;
+        let voicing = smoothest_voicing(&from, &to);
+        let motions = from
+            .notes
+            .iter()
+            .zip(voicing.iter())
+            .map(|(from_note, to_note)| format!("- {}.", describe_motion(*from_note, *to_note)))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let from_notes = join_notes(&from.notes);
+        let to_notes = join_notes(&to.notes);
+        let from_numeral = from.roman_numeral();
+        let to_numeral = to.roman_numeral();
+        let from_name = from.name();
+        let to_name = to.name();
+        let exercise_name = format!("{}_to_{}", from_numeral, to_numeral);
+        builders.push(ExerciseBuilder {
+            directory_name: exercise_name.clone(),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        In the key of {} Major, find the smoothest way to move from the {} ({})
+                        chord to the {} ({}) chord using only strings 1 to 3 (high E, B, and G).
+                    ", key.to_string(), from_name, from_numeral, to_name, to_numeral},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        The notes of the {} chord are: {}.
+
+                        The notes of the {} chord are: {}.
+
+                        The smoothest movement between both chords is:
+
+                        {}
+                    ", from_name, from_notes, to_name, to_notes, motions},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!(
+                        "{}::{}::{}",
+                        course_id,
+                        key.to_string(),
+                        exercise_name
+                    ))
+                    .name(format!(
+                        "Voice leading from {} to {} in {} Major",
+                        from_numeral,
+                        to_numeral,
+                        key.to_string()
+                    ))
+                    .clone()
+            }),
+        });
+    }
+    Ok(builders)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let course_id = *COURSE_ID;
+    let course_generator = CircleFifthsCourse {
+        directory_name: "voice_leading".to_string(),
+        course_manifest: CourseManifest {
+            id: course_id,
+            name: "Voice Leading Between Diatonic Chords".to_string(),
+            dependencies: vec![*major_scale::COURSE_ID],
+            description: Some(
+                "Learn to move smoothly between the diatonic chords of all keys.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        course_asset_builders: vec![],
+        note_alias: None,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(course_id)
+            .clone(),
+        lesson_builder_generator: Box::new(move |note, previous_note| {
+            let lesson_id = format!("{}::{}", course_id, note.to_string());
+
+            Ok(LessonBuilder {
+                directory_name: format!("lesson_{}", note.to_ascii_string()),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: Some("back.md".to_string()),
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: generate_exercise_builders(course_id, note)?,
+                manifest_closure: Box::new(move |m| {
+                    let deps = match previous_note {
+                        None => vec![],
+                        Some(previous_note) => vec![Ustr::from(&format!(
+                            "{}::{}",
+                            course_id,
+                            previous_note.to_string()
+                        ))],
+                    };
+
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(format!("{}::{}", course_id, note.to_string()))
+                        .name(format!("Voice Leading in {} Major", note.to_string()))
+                        .description(Some(format!(
+                            "Move smoothly between the diatonic chords of {} Major.",
+                            note.to_string()
+                        )))
+                        .dependencies(deps)
+                        .metadata(Some(BTreeMap::from([(
+                            MusicMetadata::Key.to_string(),
+                            vec![note.to_ascii_string()],
+                        )])))
+                        .clone()
+                }),
+            })
+        }),
+        extra_lessons_generator: None,
+    };
+    Ok(course_generator
+        .generate_course_builder()?
+        .with_instrument_metadata()
+        .with_skill_metadata(&["harmony"])
+        .with_concept_metadata(&["voice leading"])
+        .with_genre_metadata(&[Genre::Jazz, Genre::Pop])
+        .with_standard_instruction_asset(&format!(
+            "{}\n{}",
+            indoc! {"
+                Voice leading is the art of moving from one chord to the next by changing as few
+                notes as possible. Notes shared by both chords should be held, and the rest
+                should move by the smallest possible distance, usually a half or whole step.
+
+                Play the first chord on the top three strings, then find a voicing of the second
+                chord that keeps the movement of each note to a minimum. Repeat the movement
+                until you can play it without breaking the flow of the music.
+            "},
+            instructions::circle_of_fifths_note()
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::build_course_in_memory, theory::voice_leading::course_builder};
+
+    /// Verifies that the course contains a lesson for each major key, with eight exercises each.
+    #[test]
+    fn build_in_memory() -> anyhow::Result<()> {
+        let course = build_course_in_memory(&course_builder()?)?;
+        assert_eq!(course.lesson_count(), 15);
+        assert_eq!(course.exercise_count(), 120);
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Common tunings of the guitar, given as the strings from the lowest to the highest.
+
+use trane::data::music::notes::Note;
+
+use crate::fretboard::guitar_string::GuitarString;
+
+/// The standard tuning.
+pub const STANDARD: [GuitarString; 6] = [
+    GuitarString::new(Note::E, 2, 6),
+    GuitarString::new(Note::A, 2, 5),
+    GuitarString::new(Note::D, 3, 4),
+    GuitarString::new(Note::G, 3, 3),
+    GuitarString::new(Note::B, 3, 2),
+    GuitarString::new(Note::E, 4, 1),
+];
+
+/// Every string tuned down by one semitone from standard tuning.
+pub const HALF_STEP_DOWN: [GuitarString; 6] = [
+    GuitarString::new(Note::E_FLAT, 2, 6),
+    GuitarString::new(Note::A_FLAT, 2, 5),
+    GuitarString::new(Note::D_FLAT, 3, 4),
+    GuitarString::new(Note::G_FLAT, 3, 3),
+    GuitarString::new(Note::B_FLAT, 3, 2),
+    GuitarString::new(Note::E_FLAT, 4, 1),
+];
+
+/// Every string tuned down by two semitones from standard tuning.
+pub const FULL_STEP_DOWN: [GuitarString; 6] = [
+    GuitarString::new(Note::D, 2, 6),
+    GuitarString::new(Note::G, 2, 5),
+    GuitarString::new(Note::C, 3, 4),
+    GuitarString::new(Note::F, 3, 3),
+    GuitarString::new(Note::A, 3, 2),
+    GuitarString::new(Note::D, 4, 1),
+];
+
+/// The DADGAD tuning, an open tuning used in Celtic and acoustic music.
+pub const DADGAD: [GuitarString; 6] = [
+    GuitarString::new(Note::D, 2, 6),
+    GuitarString::new(Note::A, 2, 5),
+    GuitarString::new(Note::D, 3, 4),
+    GuitarString::new(Note::G, 3, 3),
+    GuitarString::new(Note::A, 3, 2),
+    GuitarString::new(Note::D, 4, 1),
+];
+
+/// The Open G tuning, in which the open strings form a G major chord.
+pub const OPEN_G: [GuitarString; 6] = [
+    GuitarString::new(Note::D, 2, 6),
+    GuitarString::new(Note::G, 2, 5),
+    GuitarString::new(Note::D, 3, 4),
+    GuitarString::new(Note::G, 3, 3),
+    GuitarString::new(Note::B, 3, 2),
+    GuitarString::new(Note::D, 4, 1),
+];
+
+/// The Open E tuning, in which the open strings form an E major chord.
+pub const OPEN_E: [GuitarString; 6] = [
+    GuitarString::new(Note::E, 2, 6),
+    GuitarString::new(Note::B, 2, 5),
+    GuitarString::new(Note::E, 3, 4),
+    GuitarString::new(Note::G_SHARP, 3, 3),
+    GuitarString::new(Note::B, 3, 2),
+    GuitarString::new(Note::E, 4, 1),
+];
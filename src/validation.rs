@@ -0,0 +1,124 @@
+//! Checks run on the generated courses before they are written to disk.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use trane::course_builder::CourseBuilder;
+
+use crate::in_memory::build_course_in_memory;
+
+/// Verifies that no two lessons or exercises in the course share the same ID. Trane cannot load a
+/// library with duplicate IDs, so the error lists all the duplicate IDs along with the number of
+/// times they appear.
+pub fn validate_course_builder(builder: &CourseBuilder) -> Result<()> {
+    let mut id_counts: BTreeMap<String, usize> = BTreeMap::new();
+    let course = build_course_in_memory(builder)?;
+    for (lesson_manifest, exercise_manifests) in course.lessons {
+        *id_counts.entry(lesson_manifest.id.to_string()).or_default() += 1;
+        for exercise_manifest in exercise_manifests {
+            *id_counts
+                .entry(exercise_manifest.id.to_string())
+                .or_default() += 1;
+        }
+    }
+
+    let duplicates = id_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(id, count)| format!("{} ({} times)", id, count))
+        .collect::<Vec<String>>();
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "course {} contains duplicate IDs: {}",
+            course.course_manifest.id,
+            duplicates.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trane::{
+        course_builder::{CourseBuilder, ExerciseBuilder, LessonBuilder},
+        data::{
+            CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+            LessonManifestBuilder,
+        },
+    };
+    use ustr::Ustr;
+
+    use crate::validation::validate_course_builder;
+
+    /// Returns a course with a single lesson whose exercises have the given IDs.
+    fn test_course(exercise_ids: &[&'static str]) -> CourseBuilder {
+        let course_id = Ustr::from("course");
+        let lesson_id = Ustr::from("course::lesson");
+        CourseBuilder {
+            directory_name: "course".to_string(),
+            course_manifest: CourseManifest {
+                id: course_id,
+                name: "Course".to_string(),
+                dependencies: vec![],
+                description: None,
+                authors: None,
+                metadata: None,
+                course_material: None,
+                course_instructions: None,
+                generator_config: None,
+            },
+            asset_builders: vec![],
+            lesson_manifest_template: LessonManifestBuilder::default()
+                .course_id(course_id)
+                .clone(),
+            lesson_builders: vec![LessonBuilder {
+                directory_name: "lesson".to_string(),
+                exercise_manifest_template: ExerciseManifestBuilder::default()
+                    .course_id(course_id)
+                    .lesson_id(lesson_id)
+                    .exercise_type(ExerciseType::Procedural)
+                    .exercise_asset(ExerciseAsset::FlashcardAsset {
+                        front_path: "front.md".to_string(),
+                        back_path: None,
+                    })
+                    .clone(),
+                asset_builders: vec![],
+                exercise_builders: exercise_ids
+                    .iter()
+                    .map(|id| {
+                        let id = *id;
+                        ExerciseBuilder {
+                            directory_name: id.to_string(),
+                            asset_builders: vec![],
+                            manifest_closure: Box::new(move |m| {
+                                #[allow(clippy::redundant_clone)]
+                                m.clone().id(id).name(id.to_string()).clone()
+                            }),
+                        }
+                    })
+                    .collect(),
+                manifest_closure: Box::new(move |m| {
+                    #[allow(clippy::redundant_clone)]
+                    m.clone()
+                        .id(lesson_id)
+                        .name("Lesson".to_string())
+                        .dependencies(vec![])
+                        .clone()
+                }),
+            }],
+        }
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        assert!(validate_course_builder(&test_course(&["exercise_1", "exercise_2"])).is_ok());
+
+        let result = validate_course_builder(&test_course(&["exercise_1", "exercise_1"]));
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("exercise_1 (2 times)"));
+    }
+}
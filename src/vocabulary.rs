@@ -0,0 +1 @@
+pub mod pentatonic_licks;
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use indoc::{formatdoc, indoc};
+use lazy_static::lazy_static;
+use trane::{
+    course_builder::{
+        music::MusicMetadata, AssetBuilder, CourseBuilder, ExerciseBuilder, LessonBuilder,
+    },
+    data::{
+        music::{notes::Note, scales::ScaleType},
+        CourseManifest, ExerciseAsset, ExerciseManifestBuilder, ExerciseType,
+        LessonManifestBuilder,
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    builder_ext::GuitarCourseBuilderExt,
+    constants::AUTHORS,
+    fretboard::minor_pentatonic_scale,
+    metadata::{Difficulty, Genre, GuitarExerciseMetadata},
+    music::{semitones, semitones_up},
+    technique::{legato, sight_reading::generate_tab_snippet},
+    tunings,
+};
+
+lazy_static! {
+    pub static ref COURSE_ID: Ustr = Ustr::from("trane::guitar::vocabulary::pentatonic_licks");
+}
+
+/// The metadata of the exercises in this course.
+const EXERCISE_METADATA: GuitarExerciseMetadata = GuitarExerciseMetadata {
+    recommended_bpm: Some(80),
+    difficulty: Some(Difficulty::Intermediate),
+    backing_track_url: None,
+};
+
+/// A lick played over the minor pentatonic scale.
+struct Lick {
+    /// The ID of the lick used in the IDs and directory names.
+    id: &'static str,
+
+    /// The name of the lick.
+    name: &'static str,
+
+    /// A description of the character of the lick.
+    character: &'static str,
+
+    /// The notes of the lick, given as the number of the string and the fret relative to the root
+    /// of the key on the sixth string.
+    notes: &'static [(u8, u8)],
+}
+
+/// The licks taught in this course, in the order in which they are introduced.
+const LICKS: [Lick; 5] = [
+    Lick {
+        id: "box_one_descent",
+        name: "Descending run in the first box",
+        character: "A steady run down the scale which ends on the root. It's the backbone of \
+            countless rock and blues solos",
+        notes: &[
+            (1, 3),
+            (1, 0),
+            (2, 3),
+            (2, 0),
+            (3, 2),
+            (3, 0),
+            (4, 2),
+            (4, 0),
+        ],
+    },
+    Lick {
+        id: "repeating_triplet",
+        name: "Repeating triplet",
+        character: "A restless, circular lick which builds tension the longer it's repeated",
+        notes: &[
+            (1, 0),
+            (2, 3),
+            (2, 0),
+            (1, 0),
+            (2, 3),
+            (2, 0),
+            (1, 0),
+            (2, 3),
+            (2, 0),
+        ],
+    },
+    Lick {
+        id: "hammer_on_roll",
+        name: "Hammer-on roll",
+        character: "A smooth and bouncy lick built around a hammer-on in the third string",
+        notes: &[
+            (3, 0),
+            (3, 2),
+            (2, 0),
+            (3, 0),
+            (3, 2),
+            (2, 0),
+            (2, 3),
+            (1, 0),
+        ],
+    },
+    Lick {
+        id: "bb_king_ending",
+        name: "B.B. King ending lick",
+        character: "A sweet, conclusive phrase in the style of B.B. King which resolves \
+            down to the root. Use it to close a solo or the end of a chorus",
+        notes: &[(1, 0), (2, 3), (2, 0), (3, 2), (3, 0), (4, 2)],
+    },
+    Lick {
+        id: "albert_king_box",
+        name: "Albert King box",
+        character: "A biting, vocal phrase in the box above the first position, where \
+            Albert King played most of his bends. Bend the first note a whole step up for the \
+            full effect",
+        notes: &[(2, 5), (1, 5), (2, 5), (2, 3), (3, 4), (3, 2), (2, 3)],
+    },
+];
+
+/// Returns the fret of the root of the given key in the sixth string. The root is played at the
+/// twelfth fret instead of the open string so that the licks can be played with fretted notes.
+fn root_fret(key: Note) -> u8 {
+    match semitones_up(Note::E, key) {
+        0 => 12,
+        fret => fret,
+    }
+}
+
+/// Returns the notes of the lick in the given key, given as the note, the string, and the fret.
+fn lick_notes(lick: &Lick, key: Note) -> Result<Vec<(Note, u8, u8)>> {
+    let scale_notes = ScaleType::MinorPentatonic.notes(key)?.notes;
+    let root_fret = root_fret(key);
+    lick.notes
+        .iter()
+        .map(|(string_number, offset)| {
+            let fret = root_fret + offset;
+            let pitch = semitones(tunings::STANDARD[6 - *string_number as usize].open_note) + fret;
+            let note = scale_notes
+                .iter()
+                .find(|note| semitones(**note) == pitch % 12)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "lick {} contains a note outside the scale in the key of {}",
+                        lick.id,
+                        key.to_string()
+                    )
+                })?;
+            Ok((*note, *string_number, fret))
+        })
+        .collect()
+}
+
+/// Generates the exercise builders for the lesson of the given lick. There's one exercise for each
+/// minor key.
+fn generate_exercise_builders(
+    lesson_id: Ustr,
+    lick: &'static Lick,
+) -> Result<Vec<ExerciseBuilder>> {
+    let mut exercises = vec![];
+    for major_key in Note::all_keys(false) {
+        let key = major_key.relative_minor()?;
+        let notes = lick_notes(lick, key)?;
+        let (_, first_string, first_fret) = notes[0];
+        let note_names = notes
+            .iter()
+            .map(|(note, _, _)| note.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        exercises.push(ExerciseBuilder {
+            directory_name: format!("key_{}", key.to_ascii_string()),
+            asset_builders: vec![
+                AssetBuilder {
+                    file_name: "front.md".to_string(),
+                    contents: formatdoc! {"
+                        Play the lick \"{}\" in {} minor.
+
+                        {}.
+
+                        The lick starts at fret {} of string {}, with the root of the key at
+                        fret {} of the sixth string.
+                    ",
+                    lick.name,
+                    key.to_string(),
+                    lick.character,
+                    first_fret,
+                    first_string,
+                    root_fret(key)},
+                },
+                AssetBuilder {
+                    file_name: "back.md".to_string(),
+                    contents: formatdoc! {"
+                        ```
+                        {}
+                        ```
+
+                        The notes of the lick are: {}.
+                    ", generate_tab_snippet(&notes), note_names},
+                },
+            ],
+            manifest_closure: Box::new(move |m| {
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(format!("{}::{}", lesson_id, key.to_string()))
+                    .name(format!("{} in {} minor", lick.name, key.to_string()))
+                    .clone()
+            }),
+        });
+    }
+    Ok(exercises)
+}
+
+pub fn course_builder() -> Result<CourseBuilder> {
+    let mut previous_lesson: Option<Ustr> = None;
+    let mut lesson_builders = vec![];
+    for lick in &LICKS {
+        let lesson_id = Ustr::from(&format!("{}::{}", *COURSE_ID, lick.id));
+        let dependencies = previous_lesson.into_iter().collect::<Vec<Ustr>>();
+        previous_lesson = Some(lesson_id);
+
+        lesson_builders.push(LessonBuilder {
+            directory_name: format!("lesson_{}", lick.id),
+            exercise_manifest_template: ExerciseManifestBuilder::default()
+                .course_id(*COURSE_ID)
+                .lesson_id(lesson_id)
+                .exercise_type(ExerciseType::Procedural)
+                .exercise_asset(ExerciseAsset::FlashcardAsset {
+                    front_path: "front.md".to_string(),
+                    back_path: Some("back.md".to_string()),
+                })
+                .clone(),
+            asset_builders: vec![],
+            exercise_builders: generate_exercise_builders(lesson_id, lick)?,
+            manifest_closure: Box::new(move |m| {
+                let mut metadata = BTreeMap::from([(
+                    MusicMetadata::ScaleType.to_string(),
+                    vec!["minor pentatonic".to_string()],
+                )]);
+                EXERCISE_METADATA.add_to(&mut metadata);
+
+                #[allow(clippy::redundant_clone)]
+                m.clone()
+                    .id(lesson_id)
+                    .name(lick.name.to_string())
+                    .description(Some(format!(
+                        "Learn the lick \"{}\" in all the minor keys.",
+                        lick.name
+                    )))
+                    .dependencies(dependencies.clone())
+                    .metadata(Some(metadata))
+                    .clone()
+            }),
+        });
+    }
+
+    Ok(CourseBuilder {
+        directory_name: "pentatonic_licks".to_string(),
+        course_manifest: CourseManifest {
+            id: *COURSE_ID,
+            name: "Pentatonic Lick Vocabulary".to_string(),
+            dependencies: vec![*minor_pentatonic_scale::COURSE_ID, *legato::COURSE_ID],
+            description: Some(
+                "Learn classic minor pentatonic licks and play them in all keys.".to_string(),
+            ),
+            authors: Some(vec![AUTHORS.to_string()]),
+            metadata: None,
+            course_material: None,
+            course_instructions: None,
+            generator_config: None,
+        },
+        asset_builders: vec![],
+        lesson_builders,
+        lesson_manifest_template: LessonManifestBuilder::default()
+            .course_id(*COURSE_ID)
+            .clone(),
+    }
+    .with_instrument_metadata()
+    .with_skill_metadata(&["improvisation"])
+    .with_concept_metadata(&["licks"])
+    .with_genre_metadata(&[Genre::Blues, Genre::Rock])
+    .with_standard_instruction_asset(indoc! {"
+        Licks are the words of the language of improvisation. Learning the classic licks
+        of the minor pentatonic scale is the fastest way to start phrasing like the
+        players who invented them.
+
+        Learn each lick slowly until it's memorized, then focus on the phrasing: vary
+        the timing, add bends and vibrato, and try to make it sound like a voice. Once
+        it feels natural, play it over a backing track and mix it with your own ideas.
+    "}))
+}